@@ -2,20 +2,334 @@
 //! and do like nice assertion messages.
 
 use alloc::borrow::Cow;
-use core::fmt::Debug;
-use core::ops::{ControlFlow, FromResidual, Try};
-use core::panic::Location;
+use core::any::{type_name, Any};
+use core::borrow::Borrow;
+use core::cell::Cell;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display};
+use core::ops::{ControlFlow, FromResidual, Not, Try};
+use core::panic::{Location, UnwindSafe};
+use core::time::Duration;
 use core::convert::Infallible;
 use core::fmt::Formatter;
+use core::hash::Hash;
+use std::collections::HashMap;
 use std::error::Error;
+use std::panic::catch_unwind;
 use std::process::{ExitCode, Termination};
+use std::sync::OnceLock;
+use std::time::Instant;
+
+/// A function used to format the message and location of a failed assertion for output
+pub type FailureFormatter = fn(&Location<'_>, &str) -> String;
+
+static FORMATTER: OnceLock<FailureFormatter> = OnceLock::new();
+
+/// Install a custom formatter for failed assertions. This is used whenever a failed assertion is
+/// output - by [`Assert::to_panic`], when dropped, or when returned from `main` or a test - and
+/// its result is output as-is on its own line. It is only preceded by a line noting that the
+/// assertion was dropped, when it was, and followed by the failure trail of a traced assertion.
+/// The formatter is always given the raw failure message. If no formatter is set, failures are
+/// formatted as `{msg} at {loc}`.
+///
+/// # Errors
+///
+/// If a formatter has already been set, returning the provided formatter
+pub fn set_formatter(f: FailureFormatter) -> Result<(), FailureFormatter> {
+    FORMATTER.set(f)
+}
+
+thread_local! {
+    static DEFUSE_ON_PANIC: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set whether failed assertions dropped while the current thread is already panicking should be
+/// logged to stderr, instead of panicking again and aborting the process. This is useful for
+/// harnesses that use [`catch_unwind`](std::panic::catch_unwind). Defaults to `false`.
+pub fn defuse_on_panic(defuse: bool) {
+    DEFUSE_ON_PANIC.with(|d| d.set(defuse));
+}
+
+#[cfg(test)]
+thread_local! {
+    static TEST_FORMATTER: Cell<Option<FailureFormatter>> = const { Cell::new(None) };
+}
+
+fn formatter() -> Option<FailureFormatter> {
+    #[cfg(test)]
+    if let Some(f) = TEST_FORMATTER.with(Cell::get) {
+        return Some(f);
+    }
+    FORMATTER.get().copied()
+}
+
+/// The ways a failed assertion can be output
+#[derive(Copy, Clone)]
+enum Sink {
+    Panic,
+    Drop,
+    DropWhilePanicking,
+    Report,
+}
+
+/// Format a failure for output to `sink`. A custom formatter's output is used as-is, otherwise
+/// the failure is formatted as `{msg} at {loc}` with a prefix depending on the sink. Either way,
+/// a dropped assertion is preceded by a line saying so. With the
+/// `color` feature enabled, the compared operands are colorized if the stream the sink writes to
+/// is a terminal and `NO_COLOR` isn't set.
+fn render_failure(loc: &Location<'_>, msg: &str, trail: &[&Location<'_>], sink: Sink) -> String {
+    let (note, prefix) = match sink {
+        Sink::Panic => ("", ""),
+        Sink::Drop => (
+            "Failed assertion dropped. (Did you forget a `?` or `to_panic`?)\n",
+            "Assertion Failed: ",
+        ),
+        Sink::DropWhilePanicking => {
+            ("Failed assertion dropped while panicking\n", "Assertion Failed: ")
+        }
+        Sink::Report => ("", "Assertion Failed: "),
+    };
+    if let Some(f) = formatter() {
+        return format!("{note}{}{}", f(loc, msg), render_trail(trail));
+    }
+
+    #[cfg(feature = "color")]
+    let msg = if use_color(sink) {
+        colorize_operands(msg)
+    } else {
+        Cow::Borrowed(msg)
+    };
+    format!("{note}{prefix}{msg} at {loc}{}", render_trail(trail))
+}
+
+#[cfg(all(test, feature = "color"))]
+thread_local! {
+    static TEST_COLOR: Cell<Option<bool>> = const { Cell::new(Some(false)) };
+}
+
+#[cfg(feature = "color")]
+fn use_color(sink: Sink) -> bool {
+    use std::io::IsTerminal;
+
+    #[cfg(test)]
+    if let Some(color) = TEST_COLOR.with(Cell::get) {
+        return color;
+    }
+    let is_terminal = match sink {
+        // Panic messages are printed to stderr by the panic hook
+        Sink::Panic | Sink::Drop | Sink::DropWhilePanicking => std::io::stderr().is_terminal(),
+        Sink::Report => std::io::stdout().is_terminal(),
+    };
+    is_terminal && std::env::var_os("NO_COLOR").is_none()
+}
+
+/// Color the operands of a message of the form "Expected `a` ... `b` ...", the first red and the
+/// second green. Other messages are returned unchanged.
+#[cfg(feature = "color")]
+fn colorize_operands(msg: &str) -> Cow<'_, str> {
+    const RED: &str = "\x1b[31m";
+    const GREEN: &str = "\x1b[32m";
+    const RESET: &str = "\x1b[0m";
+
+    let Some(rest) = msg.strip_prefix("Expected `") else {
+        return Cow::Borrowed(msg);
+    };
+    match rest.splitn(4, '`').collect::<Vec<_>>()[..] {
+        [a, cmp, b, rest] => Cow::Owned(format!(
+            "Expected `{RED}{a}{RESET}`{cmp}`{GREEN}{b}{RESET}`{rest}"
+        )),
+        _ => Cow::Borrowed(msg),
+    }
+}
+
+/// Render the trail of a failure, newest location first
+fn render_trail(trail: &[&Location<'_>]) -> String {
+    let mut out = String::new();
+    if !trail.is_empty() {
+        out.push_str("\nFailure trail:");
+        for loc in trail.iter().rev() {
+            out.push_str(&format!("\n  at {loc}"));
+        }
+    }
+    out
+}
+
+fn cmp_failure_msg<T: Debug + ?Sized, U: Debug + ?Sized>(a: &T, b: &U, cmp: &str) -> String {
+    let (a, b) = (format!("{a:?}"), format!("{b:?}"));
+    let mut msg = format!("Expected `{a}` {cmp} `{b}`");
+    let (ty_a, ty_b) = (type_name::<T>(), type_name::<U>());
+    if a == b && ty_a != ty_b {
+        msg.push_str(&format!(
+            ". Both are formatted the same, but have types `{ty_a}` and `{ty_b}`"
+        ));
+    }
+    msg
+}
+
+/// Heuristically check whether `val` is, or contains, a float NaN. The operands of
+/// [`Assert::eq`] aren't `'static`, so they can't be downcast and inspected directly. Instead, this
+/// looks for a `NaN` token outside of string literals in the debug output of values whose type
+/// name mentions `f32` or `f64`. That catches e.g. `vec![f64::NAN]` but not an enum variant named
+/// `NaN`, and misses a NaN inside a user type whose name doesn't mention a float.
+fn contains_nan<T: Debug + ?Sized>(val: &T) -> bool {
+    fn has_token(s: &str, f: impl Fn(&str) -> bool) -> bool {
+        s.split(|c: char| !c.is_alphanumeric() && c != '_').any(f)
+    }
+
+    let debug = format!("{val:?}");
+    let mut unquoted = String::with_capacity(debug.len());
+    let (mut in_str, mut escaped) = (false, false);
+    for c in debug.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_str => escaped = true,
+            '"' => in_str = !in_str,
+            _ if in_str => (),
+            _ => unquoted.push(c),
+        }
+    }
+
+    has_token(type_name::<T>(), |t| t == "f32" || t == "f64")
+        && has_token(&unquoted, |t| t == "NaN")
+}
+
+/// Compare two slices of floats element-wise, shared by the `slices_approx_eq` variants. Equal
+/// elements always match, so that equal infinities do, otherwise `close` decides.
+#[track_caller]
+fn slices_approx_eq_by<T: Copy + PartialEq + Display>(
+    a: &[T],
+    b: &[T],
+    epsilon: &dyn Display,
+    close: impl Fn(T, T) -> bool,
+) -> Assert {
+    if a.len() != b.len() {
+        return Assert::failure().msg(format!(
+            "Expected slices of equal length, got lengths `{}` and `{}`",
+            a.len(),
+            b.len()
+        ));
+    }
+
+    let pos = a.iter().zip(b).position(|(&x, &y)| x != y && !close(x, y));
+    match pos {
+        Some(idx) => Assert::failure().msg(format!(
+            "Expected `{}` to approximately equal `{}` at index {idx} (epsilon `{epsilon}`)",
+            a[idx], b[idx]
+        )),
+        None => Assert::success(),
+    }
+}
+
+/// Produce a line-based diff of the pretty debug renderings of `a` and `b`, if either spans
+/// multiple lines
+#[cfg(feature = "diff")]
+fn debug_diff<T: Debug + ?Sized, U: Debug + ?Sized>(a: &T, b: &U) -> String {
+    use similar::{ChangeTag, TextDiff};
+
+    let (a, b) = (format!("{a:#?}"), format!("{b:#?}"));
+    if !a.contains('\n') && !b.contains('\n') {
+        return String::new();
+    }
+
+    let mut out = String::from("\n\nDiff (- left, + right):\n");
+    for change in TextDiff::from_lines(&a, &b).iter_all_changes() {
+        out.push(match change.tag() {
+            ChangeTag::Delete => '-',
+            ChangeTag::Insert => '+',
+            ChangeTag::Equal => ' ',
+        });
+        out.push_str(change.value());
+        if change.missing_newline() {
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Find the path of the first difference between two JSON values, if there is one
+#[cfg(feature = "serde_json")]
+fn json_diff_path(a: &serde_json::Value, b: &serde_json::Value, path: String) -> Option<String> {
+    use serde_json::Value;
+
+    match (a, b) {
+        (Value::Object(a), Value::Object(b)) => {
+            for (key, val) in a {
+                let path = format!("{path}.{key}");
+                match b.get(key) {
+                    Some(other) => {
+                        if let Some(path) = json_diff_path(val, other, path) {
+                            return Some(path);
+                        }
+                    }
+                    None => return Some(path),
+                }
+            }
+            b.keys()
+                .find(|key| !a.contains_key(*key))
+                .map(|key| format!("{path}.{key}"))
+        }
+        (Value::Array(a), Value::Array(b)) => {
+            for (idx, (x, y)) in a.iter().zip(b).enumerate() {
+                if let Some(path) = json_diff_path(x, y, format!("{path}[{idx}]")) {
+                    return Some(path);
+                }
+            }
+            (a.len() != b.len()).then(|| format!("{path}[{}]", a.len().min(b.len())))
+        }
+        _ => (a != b).then_some(path),
+    }
+}
+
+/// Render the debug representation of `val`, truncated to at most `max_len` characters. Returns
+/// whether truncation occurred.
+fn truncated_debug<T: Debug + ?Sized>(val: &T, max_len: usize) -> (String, bool) {
+    let mut out = format!("{val:?}");
+    match out.char_indices().nth(max_len) {
+        Some((idx, _)) => {
+            out.truncate(idx);
+            out.push_str("...");
+            (out, true)
+        }
+        None => (out, false),
+    }
+}
+
+fn panic_msg(payload: &(dyn Any + Send)) -> &str {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+#[track_caller]
+fn unordered_result<T: Debug>(a: &[T], b: &[T], only_left: &[&T], only_right: &[&T]) -> Assert {
+    if only_left.is_empty() && only_right.is_empty() {
+        Assert::success()
+    } else {
+        Assert::failure().msg(format!(
+            "Expected `{a:?}` to equal `{b:?}` ignoring order. Only in left: `{only_left:?}`, \
+             only in right: `{only_right:?}`"
+        ))
+    }
+}
 
 /// The residual type for a failed assertion
-pub struct AssertResidual(&'static Location<'static>, Cow<'static, str>);
+pub struct AssertResidual(
+    &'static Location<'static>,
+    Cow<'static, str>,
+    Vec<&'static Location<'static>>,
+);
 
 enum AssertInner {
     Success,
-    Failure(&'static Location<'static>, Cow<'static, str>),
+    Failure(
+        &'static Location<'static>,
+        Cow<'static, str>,
+        Vec<&'static Location<'static>>,
+    ),
 }
 
 /// A struct representing a logical assertion made at runtime. This assertion must be consumed in
@@ -105,6 +419,7 @@ impl Assert {
         Assert(AssertInner::Failure(
             Location::caller(),
             Cow::from("Assertion failed"),
+            Vec::new(),
         ))
     }
 
@@ -128,7 +443,60 @@ impl Assert {
         }
     }
 
+    /// Create an assertion from a `Result`, which succeeds if it is `Ok` and fails with the
+    /// error's message if it is `Err`. Unlike the `From` implementation, this only requires the
+    /// error to implement [`Display`].
+    #[track_caller]
+    pub fn from_result<T, E: Display>(r: Result<T, E>) -> Assert {
+        match r {
+            Ok(_) => Assert::success(),
+            Err(err) => Assert::failure().msg(err.to_string()),
+        }
+    }
+
+    /// Create an assertion that fails with the provided message if `cond` is true, and succeeds
+    /// otherwise
+    #[track_caller]
+    pub fn fail_if(cond: bool, msg: impl Into<Cow<'static, str>>) -> Assert {
+        if cond {
+            Assert::failure().msg(msg.into())
+        } else {
+            Assert::success()
+        }
+    }
+
+    /// Create an assertion that succeeds if `cond` is true, and fails with the provided message
+    /// otherwise
+    #[track_caller]
+    pub fn succeed_if(cond: bool, msg: impl Into<Cow<'static, str>>) -> Assert {
+        Assert::fail_if(!cond, msg)
+    }
+
+    /// Assert that a value satisfies the predicate `pred`. On failure, reports the value along
+    /// with `desc`, which should describe the condition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::assert::Assert;
+    /// # fn main() -> Assert {
+    /// let n = 16u32;
+    /// Assert::check(&n, |n| n.is_power_of_two(), "be a power of two")
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn check<T: Debug>(val: &T, pred: impl FnOnce(&T) -> bool, desc: &str) -> Assert {
+        if pred(val) {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!("Expected `{val:?}` to satisfy {desc}"))
+        }
+    }
+
     /// Assert that two values are equal
+    ///
+    /// With the `diff` feature enabled, the failure message also includes a line-based diff of the
+    /// pretty debug renderings of the values, if they span multiple lines.
     #[track_caller]
     pub fn eq<T, U>(a: &T, b: &U) -> Assert
     where
@@ -138,7 +506,16 @@ impl Assert {
         if a == b {
             Assert::success()
         } else {
-            Assert::failure().msg(format!("Expected `{a:?}` to equal `{b:?}`"))
+            let mut msg = cmp_failure_msg(a, b, "to equal");
+            if contains_nan(a) || contains_nan(b) {
+                msg.push_str(
+                    ". NaN is never equal to anything, including itself - consider checking \
+                     `is_nan`, or comparing with `Assert::eq_with`",
+                );
+            }
+            #[cfg(feature = "diff")]
+            let msg = msg + &debug_diff(a, b);
+            Assert::failure().msg(msg)
         }
     }
 
@@ -152,205 +529,1405 @@ impl Assert {
         if a != b {
             Assert::success()
         } else {
-            Assert::failure().msg(format!("Expected `{a:?}` to not equal `{b:?}`"))
+            Assert::failure().msg(cmp_failure_msg(a, b, "to not equal"))
         }
     }
 
-    /// Attach a custom message to an assertion. This message is discarded if the assertion was
-    /// successful.
-    pub fn msg<S>(self, msg: S) -> Assert
+    /// Assert that the values in each pair are equal. On failure, reports the index and values of
+    /// the first mismatched pair.
+    #[track_caller]
+    pub fn all_eq<T: Debug + PartialEq>(pairs: &[(T, T)]) -> Assert {
+        Assert::all_eq_iter(pairs.iter().map(|(a, b)| (a, b)))
+    }
+
+    /// Assert that the values in each pair yielded by an iterator are equal, such as a table of
+    /// expected and actual values. On failure, reports the index and values of the first
+    /// mismatched pair.
+    #[track_caller]
+    pub fn all_eq_iter<T, U>(pairs: impl IntoIterator<Item = (T, U)>) -> Assert
     where
-        Cow<'static, str>: From<S>,
+        T: Debug + PartialEq<U>,
+        U: Debug,
     {
-        Assert(match self.inner_defuse() {
-            AssertInner::Failure(loc, _) => AssertInner::Failure(loc, msg.into()),
-            AssertInner::Success => AssertInner::Success,
-        })
+        for (idx, (a, b)) in pairs.into_iter().enumerate() {
+            if a != b {
+                return Assert::failure()
+                    .msg(format!("Expected `{a:?}` to equal `{b:?}` at index {idx}"));
+            }
+        }
+        Assert::success()
     }
 
-    /// Attach a custom message to an assertion, only calling the provided function to generate
-    /// the message if the assertion failed.
-    pub fn with_msg(self, f: impl FnOnce() -> String) -> Assert {
-        Assert(match self.inner_defuse() {
-            AssertInner::Failure(loc, _) => AssertInner::Failure(loc, Cow::from(f())),
-            AssertInner::Success => AssertInner::Success,
-        })
+    /// Assert that two iterators yield equal items, comparing them lazily. On failure, reports the
+    /// index of the first differing item, or which iterator ran out early.
+    #[track_caller]
+    pub fn iter_eq<T, U>(a: impl IntoIterator<Item = T>, b: impl IntoIterator<Item = U>) -> Assert
+    where
+        T: Debug + PartialEq<U>,
+        U: Debug,
+    {
+        let (mut a, mut b) = (a.into_iter(), b.into_iter());
+        let mut idx = 0;
+        loop {
+            match (a.next(), b.next()) {
+                (None, None) => return Assert::success(),
+                (Some(x), Some(y)) if x == y => idx += 1,
+                (Some(x), Some(y)) => {
+                    return Assert::failure()
+                        .msg(format!("Expected `{x:?}` to equal `{y:?}` at index {idx}"))
+                }
+                (None, Some(y)) => {
+                    return Assert::failure().msg(format!(
+                        "Expected iterators to be equal, but left ended at index {idx} while \
+                         right yielded `{y:?}`"
+                    ))
+                }
+                (Some(x), None) => {
+                    return Assert::failure().msg(format!(
+                        "Expected iterators to be equal, but right ended at index {idx} while \
+                         left yielded `{x:?}`"
+                    ))
+                }
+            }
+        }
     }
 
-    /// Convert this assertion to a panic if it failed, or do nothing on a success.
+    /// Assert that two values are equal. On failure, the debug output of each value is truncated
+    /// to at most `max_len` characters, keeping messages readable for large values. Truncated
+    /// output ends with `...`, and the message notes that truncation occurred.
+    #[track_caller]
+    pub fn eq_shallow<T, U>(a: &T, b: &U, max_len: usize) -> Assert
+    where
+        T: Debug + PartialEq<U>,
+        U: Debug,
+    {
+        if a == b {
+            Assert::success()
+        } else {
+            let (a, a_truncated) = truncated_debug(a, max_len);
+            let (b, b_truncated) = truncated_debug(b, max_len);
+            let mut msg = format!("Expected `{a}` to equal `{b}`");
+            if a_truncated || b_truncated {
+                msg.push_str(&format!(" (truncated to {max_len} characters)"));
+            }
+            Assert::failure().msg(msg)
+        }
+    }
+
+    /// Assert that two values are equal after borrowing both as `B`, such as comparing a `String`
+    /// with a `&str`. Since many types can be borrowed as several others, `B` usually can't be
+    /// inferred and must be specified.
     ///
-    /// # Panics
+    /// # Examples
     ///
-    /// If the assertion failed
-    pub fn to_panic(self) {
-        if let AssertInner::Failure(loc, msg) = self.inner_defuse() {
-            panic!("{msg} at {loc}")
+    /// ```
+    /// # use trying::assert::Assert;
+    /// # fn main() -> Assert {
+    /// let owned = String::from("lit");
+    /// Assert::eq_borrow::<_, _, str>(&owned, &"lit")
+    /// # }
+    /// ```
+    #[track_caller]
+    pub fn eq_borrow<T, U, B>(a: &T, b: &U) -> Assert
+    where
+        T: Borrow<B>,
+        U: Borrow<B>,
+        B: ?Sized + Debug + PartialEq,
+    {
+        let (a, b) = (a.borrow(), b.borrow());
+        if a == b {
+            Assert::success()
+        } else {
+            Assert::failure().msg(cmp_failure_msg(a, b, "to equal"))
         }
     }
 
-    /// Consume this assertion harmlessly, doing nothing. This is probably not what you want,
-    /// unless you really need to ignore a failed assertion for some reason.
-    pub fn defuse(self) {
-        self.inner_defuse();
+    /// Assert that two values are equal, using the provided message on failure. Unlike
+    /// [`eq`](Self::eq), this doesn't require the values to implement `Debug`.
+    #[track_caller]
+    pub fn eq_msg<T, U>(a: &T, b: &U, msg: &str) -> Assert
+    where
+        T: PartialEq<U>,
+    {
+        if a == b {
+            Assert::success()
+        } else {
+            Assert::failure().msg(msg.to_owned())
+        }
     }
 
-    /// Check whether this assertion failed
-    #[must_use]
-    pub const fn is_failure(&self) -> bool {
-        matches!(self.0, AssertInner::Failure(..))
+    /// Assert that two values are equal, calling `msg` to generate the failure message only if the
+    /// values are unequal. Like [`eq_msg`](Self::eq_msg), this doesn't require the values to
+    /// implement `Debug`.
+    #[track_caller]
+    pub fn eq_lazy<T, U>(a: &T, b: &U, msg: impl FnOnce() -> String) -> Assert
+    where
+        T: PartialEq<U>,
+    {
+        if a == b {
+            Assert::success()
+        } else {
+            Assert::failure().msg(msg())
+        }
     }
 
-    /// Check whether this assertion succeeded
-    #[must_use]
-    pub const fn is_success(&self) -> bool {
-        matches!(self.0, AssertInner::Success)
+    /// Assert that two values are equal, using a custom comparison function
+    #[track_caller]
+    pub fn eq_with<T, U>(a: &T, b: &U, cmp: impl FnOnce(&T, &U) -> bool) -> Assert
+    where
+        T: Debug,
+        U: Debug,
+    {
+        if cmp(a, b) {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!("Expected `{a:?}` to equal `{b:?}`"))
+        }
     }
-}
 
-impl Drop for Assert {
-    fn drop(&mut self) {
-        if let AssertInner::Failure(_, _) = self.0 {
-            panic!("Failed assertion dropped. (Did you forget a `?` or `to_panic`?)\n{self:?}");
+    /// Assert that two values are not equal, using a custom comparison function. The function
+    /// should return `true` if the values are equal.
+    #[track_caller]
+    pub fn ne_with<T, U>(a: &T, b: &U, cmp: impl FnOnce(&T, &U) -> bool) -> Assert
+    where
+        T: Debug,
+        U: Debug,
+    {
+        if !cmp(a, b) {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!("Expected `{a:?}` to not equal `{b:?}`"))
         }
     }
-}
 
-impl Debug for Assert {
-    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
-        match &self.0 {
-            AssertInner::Failure(loc, msg) => {
-                write!(f, "Assertion Failed: {msg} at {loc}")
-            }
-            AssertInner::Success => {
-                write!(f, "Assertion Successful")
-            }
+    /// Assert that a slice is sorted in ascending order. On failure, reports the first pair of
+    /// elements that are out of order.
+    #[track_caller]
+    pub fn is_sorted<T: PartialOrd + Debug>(slice: &[T]) -> Assert {
+        Assert::is_sorted_by(slice, |a, b| a <= b)
+    }
+
+    /// Assert that a slice is sorted, using a comparison function which should return `true` if
+    /// its two arguments are in order. See [`is_sorted`](Self::is_sorted).
+    #[track_caller]
+    pub fn is_sorted_by<T: Debug>(slice: &[T], mut cmp: impl FnMut(&T, &T) -> bool) -> Assert {
+        match slice.windows(2).position(|w| !cmp(&w[0], &w[1])) {
+            Some(idx) => Assert::failure().msg(format!(
+                "Expected slice to be sorted, but `{:?}` at index {idx} is out of order with \
+                 `{:?}` at index {}",
+                slice[idx],
+                slice[idx + 1],
+                idx + 1
+            )),
+            None => Assert::success(),
         }
     }
-}
 
-impl Try for Assert {
-    type Output = ();
-    type Residual = AssertResidual;
+    /// Assert that two slices contain the same elements, ignoring order. On failure, reports the
+    /// elements only present in one of the slices.
+    #[track_caller]
+    pub fn eq_unordered<T: Ord + Debug>(a: &[T], b: &[T]) -> Assert {
+        let mut left = a.iter().collect::<Vec<_>>();
+        let mut right = b.iter().collect::<Vec<_>>();
+        left.sort();
+        right.sort();
 
-    fn from_output(_: Self::Output) -> Self {
-        Assert::success()
+        let mut only_left = Vec::new();
+        let mut only_right = Vec::new();
+        let (mut l, mut r) = (left.into_iter().peekable(), right.into_iter().peekable());
+        loop {
+            match (l.peek(), r.peek()) {
+                (Some(x), Some(y)) => match x.cmp(y) {
+                    Ordering::Less => only_left.extend(l.next()),
+                    Ordering::Greater => only_right.extend(r.next()),
+                    Ordering::Equal => {
+                        l.next();
+                        r.next();
+                    }
+                },
+                (Some(_), None) => only_left.extend(l.next()),
+                (None, Some(_)) => only_right.extend(r.next()),
+                (None, None) => break,
+            }
+        }
+
+        unordered_result(a, b, &only_left, &only_right)
     }
 
-    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
-        match self.inner_defuse() {
-            AssertInner::Failure(loc, msg) => ControlFlow::Break(AssertResidual(loc, msg)),
-            AssertInner::Success => ControlFlow::Continue(()),
+    /// Assert that two slices contain the same elements, ignoring order, for types which are
+    /// [`Hash`] but not [`Ord`]. See [`eq_unordered`](Self::eq_unordered).
+    #[track_caller]
+    pub fn eq_unordered_hash<T: Eq + Hash + Debug>(a: &[T], b: &[T]) -> Assert {
+        let mut counts = HashMap::<&T, isize>::new();
+        for item in a {
+            *counts.entry(item).or_default() += 1;
+        }
+        for item in b {
+            *counts.entry(item).or_default() -= 1;
+        }
+
+        let mut only_left = Vec::new();
+        let mut only_right = Vec::new();
+        for item in a {
+            if let Some(count) = counts.get_mut(item).filter(|c| **c > 0) {
+                *count -= 1;
+                only_left.push(item);
+            }
+        }
+        for item in b {
+            if let Some(count) = counts.get_mut(item).filter(|c| **c < 0) {
+                *count += 1;
+                only_right.push(item);
+            }
         }
+
+        unordered_result(a, b, &only_left, &only_right)
     }
-}
 
-impl FromResidual for Assert {
-    fn from_residual(residual: <Self as Try>::Residual) -> Self {
-        Assert(AssertInner::Failure(residual.0, residual.1))
+    /// Assert that two slices of floats have the same length, and each pair of elements differs by
+    /// no more than `epsilon`. Equal infinities are considered equal, while `NaN` never is. On
+    /// failure, reports the first index that diverges.
+    #[track_caller]
+    pub fn slices_approx_eq(a: &[f64], b: &[f64], epsilon: f64) -> Assert {
+        slices_approx_eq_by(a, b, &epsilon, |x, y| (x - y).abs() <= epsilon)
     }
-}
 
-impl<E> FromResidual<Result<Infallible, E>> for Assert
-where
-    E: Error,
-{
+    /// Assert that two slices of floats have the same length, and each pair of elements differs by
+    /// no more than `epsilon`. See [`slices_approx_eq`](Self::slices_approx_eq).
     #[track_caller]
-    fn from_residual(residual: Result<Infallible, E>) -> Self {
-        match residual {
-            Ok(val) => match val {},
-            Err(err) => Assert::failure().msg(err.to_string())
-        }
+    pub fn slices_approx_eq_f32(a: &[f32], b: &[f32], epsilon: f32) -> Assert {
+        slices_approx_eq_by(a, b, &epsilon, |x, y| (x - y).abs() <= epsilon)
     }
-}
 
-impl FromResidual<Option<Infallible>> for Assert {
+    /// Assert that two durations differ by at most `tolerance`
     #[track_caller]
-    fn from_residual(residual: Option<Infallible>) -> Self {
-        match residual {
-            Some(val) => match val {},
-            None => Assert::failure().msg("Expected `Option::Some`, got `Option::None`"),
+    pub fn duration_approx_eq(a: Duration, b: Duration, tolerance: Duration) -> Assert {
+        let diff = a.abs_diff(b);
+        if diff <= tolerance {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!(
+                "Expected `{a:?}` to be within `{tolerance:?}` of `{b:?}`, but they differ by \
+                 `{diff:?}`"
+            ))
         }
     }
-}
 
-impl Termination for Assert {
+    /// Assert that a string matches a regular expression. Fails if the pattern doesn't match, or
+    /// isn't a valid regex.
+    ///
+    /// The pattern is compiled on every call, which is relatively expensive. When checking many
+    /// strings against the same pattern in a hot loop, prefer compiling a `Regex` yourself.
+    #[cfg(feature = "regex")]
+    #[track_caller]
+    pub fn matches_regex(haystack: &str, pattern: &str) -> Assert {
+        match regex::Regex::new(pattern) {
+            Ok(re) if re.is_match(haystack) => Assert::success(),
+            Ok(_) => {
+                Assert::failure().msg(format!("Expected `{haystack:?}` to match regex `{pattern}`"))
+            }
+            Err(err) => Assert::failure().msg(format!("Invalid regex `{pattern}`: {err}")),
+        }
+    }
+
+    /// Assert that two JSON values are structurally equal. Object keys are compared regardless of
+    /// order. On failure, reports the path of the first difference.
+    #[cfg(feature = "serde_json")]
+    #[track_caller]
+    pub fn json_eq(a: &serde_json::Value, b: &serde_json::Value) -> Assert {
+        match json_diff_path(a, b, String::from("$")) {
+            Some(path) => Assert::failure().msg(format!(
+                "Expected `{a}` to equal `{b}`, first difference at `{path}`"
+            )),
+            None => Assert::success(),
+        }
+    }
+
+    /// Assert that a result is an error of the concrete type `E`. On failure, reports the expected
+    /// type along with the actual value or error.
+    #[track_caller]
+    pub fn err_is<E: Error + 'static>(result: &Result<impl Debug, Box<dyn Error>>) -> Assert {
+        match result {
+            Ok(val) => Assert::failure().msg(format!(
+                "Expected an error of type `{}`, got `Ok({val:?})`",
+                type_name::<E>()
+            )),
+            Err(err) if err.is::<E>() => Assert::success(),
+            Err(err) => Assert::failure().msg(format!(
+                "Expected an error of type `{}`, got `{err:?}`",
+                type_name::<E>()
+            )),
+        }
+    }
+
+    /// Assert that an error, or any error in its chain of [`sources`](Error::source), is of the
+    /// concrete type `E`
+    #[track_caller]
+    pub fn source_is<E: Error + 'static>(err: &(dyn Error + 'static)) -> Assert {
+        let mut cur = Some(err);
+        while let Some(err) = cur {
+            if err.is::<E>() {
+                return Assert::success();
+            }
+            cur = err.source();
+        }
+        Assert::failure().msg(format!(
+            "Expected `{err:?}` or one of its sources to be of type `{}`",
+            type_name::<E>()
+        ))
+    }
+
+    /// Assert that two pointers are equal, pointing to the same object
+    #[track_caller]
+    pub fn ptr_eq<T: ?Sized>(a: *const T, b: *const T) -> Assert {
+        if core::ptr::eq(a, b) {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!("Expected `{a:p}` to equal pointer `{b:p}`"))
+        }
+    }
+
+    /// Assert that two references point to the same object. See [`ptr_eq`](Self::ptr_eq).
+    #[track_caller]
+    pub fn ref_eq<T: ?Sized>(a: &T, b: &T) -> Assert {
+        Assert::ptr_eq(a, b)
+    }
+
+    /// Assert that `f` does not panic. If it does, the panic is caught and the assertion fails
+    /// with the panic message.
+    #[track_caller]
+    pub fn catch<R>(f: impl FnOnce() -> R + UnwindSafe) -> Assert {
+        match catch_unwind(f) {
+            Ok(_) => Assert::success(),
+            Err(payload) => Assert::failure().msg(format!(
+                "Expected no panic, but panicked with `{}`",
+                panic_msg(&*payload)
+            )),
+        }
+    }
+
+    /// Assert that `f` panics. The panic is caught, and the assertion fails if `f` returns
+    /// normally.
+    #[track_caller]
+    pub fn catch_panic<R>(f: impl FnOnce() -> R + UnwindSafe) -> Assert {
+        match catch_unwind(f) {
+            Ok(_) => Assert::failure().msg("Expected a panic, but none occurred"),
+            Err(_) => Assert::success(),
+        }
+    }
+
+    /// Assert that `f` panics with a message containing `expected`. The panic is caught, and the
+    /// assertion fails if `f` returns normally, or if the panic message doesn't match.
+    #[track_caller]
+    pub fn panics_with(f: impl FnOnce() + UnwindSafe, expected: &str) -> Assert {
+        match catch_unwind(f) {
+            Ok(()) => Assert::failure()
+                .msg(format!("Expected a panic containing `{expected}`, but none occurred")),
+            Err(payload) => {
+                let msg = panic_msg(&*payload);
+                if msg.contains(expected) {
+                    Assert::success()
+                } else {
+                    Assert::failure().msg(format!(
+                        "Expected a panic containing `{expected}`, but panicked with `{msg}`"
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Assert that `f` completes within `dur`. Only the call to `f` is timed, using [`Instant`],
+    /// so building the closure isn't included in the measurement.
+    #[track_caller]
+    pub fn within(dur: Duration, f: impl FnOnce()) -> Assert {
+        let start = Instant::now();
+        f();
+        let elapsed = start.elapsed();
+        if elapsed <= dur {
+            Assert::success()
+        } else {
+            Assert::failure().msg(format!(
+                "Expected to complete within `{dur:?}`, but took `{elapsed:?}`"
+            ))
+        }
+    }
+
+    /// Attach a custom message to an assertion. This message is discarded if the assertion was
+    /// successful.
+    pub fn msg<S>(self, msg: S) -> Assert
+    where
+        Cow<'static, str>: From<S>,
+    {
+        Assert(match self.inner_defuse() {
+            AssertInner::Failure(loc, _, trail) => AssertInner::Failure(loc, msg.into(), trail),
+            AssertInner::Success => AssertInner::Success,
+        })
+    }
+
+    /// Attach a custom message to an assertion, only calling the provided function to generate
+    /// the message if the assertion failed.
+    pub fn with_msg(self, f: impl FnOnce() -> String) -> Assert {
+        Assert(match self.inner_defuse() {
+            AssertInner::Failure(loc, _, trail) => AssertInner::Failure(loc, Cow::from(f()), trail),
+            AssertInner::Success => AssertInner::Success,
+        })
+    }
+
+    /// Append `: {extra}` to the failure message of an assertion. Used by `try_assert_eq!` and
+    /// `try_assert_ne!` to add a user message to the default one.
+    #[doc(hidden)]
+    pub fn __append_msg(self, extra: impl FnOnce() -> String) -> Assert {
+        Assert(match self.inner_defuse() {
+            AssertInner::Failure(loc, msg, trail) => {
+                AssertInner::Failure(loc, Cow::from(format!("{msg}: {}", extra())), trail)
+            }
+            AssertInner::Success => AssertInner::Success,
+        })
+    }
+
+    /// Attach a custom message to an assertion, formatted from a [`Display`] value. The value is
+    /// only formatted if the assertion failed.
+    pub fn msg_display(self, val: impl Display) -> Assert {
+        self.with_msg(|| val.to_string())
+    }
+
+    /// Convert this assertion into a `Result`, which is `Ok(val)` if it succeeded, or `Err` with
+    /// the failed assertion otherwise. This allows keeping a value after checking it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::assert::Assert;
+    /// fn parse(s: &str) -> Result<u32, Assert> {
+    ///     let val = s.parse().unwrap_or(0);
+    ///     Assert::ne(&val, &0).then_ret(val)
+    /// }
+    /// assert_eq!(parse("5").unwrap(), 5);
+    /// ```
+    pub fn then_ret<T>(self, val: T) -> Result<T, Assert> {
+        match self.inner_defuse() {
+            AssertInner::Success => Ok(val),
+            inner => Err(Assert(inner)),
+        }
+    }
+
+    /// Add `loc` to the failure trail of this assertion, if it failed. The trail is output after
+    /// the failure location, with the most recently added location first. This is useful for
+    /// reusable assertion helpers, to record where they were called from.
+    pub fn trace(self, loc: &'static Location<'static>) -> Assert {
+        Assert(match self.inner_defuse() {
+            AssertInner::Failure(orig, msg, mut trail) => {
+                trail.push(loc);
+                AssertInner::Failure(orig, msg, trail)
+            }
+            AssertInner::Success => AssertInner::Success,
+        })
+    }
+
+    /// Add the caller's location to the failure trail of this assertion, if it failed. See
+    /// [`trace`](Self::trace).
+    #[track_caller]
+    pub fn trace_caller(self) -> Assert {
+        self.trace(Location::caller())
+    }
+
+    /// Convert this assertion to a panic if it failed, or do nothing on a success.
+    ///
+    /// # Panics
+    ///
+    /// If the assertion failed
+    pub fn to_panic(self) {
+        if let AssertInner::Failure(loc, msg, trail) = self.inner_defuse() {
+            panic!("{}", render_failure(loc, &msg, &trail, Sink::Panic))
+        }
+    }
+
+    /// Consume this assertion harmlessly, doing nothing. This is probably not what you want,
+    /// unless you really need to ignore a failed assertion for some reason.
+    pub fn defuse(self) {
+        self.inner_defuse();
+    }
+
+    /// Render this assertion as a JSON object, for consumption by other tools. Returns `None` if
+    /// the assertion succeeded, otherwise an object of the form
+    /// `{ "message": .., "location": { "file": .., "line": .., "column": .. } }`.
+    #[cfg(feature = "serde_json")]
+    #[must_use]
+    pub fn to_json(&self) -> Option<String> {
+        match &self.0 {
+            AssertInner::Failure(loc, msg, _) => Some(
+                serde_json::json!({
+                    "message": msg,
+                    "location": {
+                        "file": loc.file(),
+                        "line": loc.line(),
+                        "column": loc.column(),
+                    },
+                })
+                .to_string(),
+            ),
+            AssertInner::Success => None,
+        }
+    }
+
+    /// Check whether this assertion failed
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        matches!(self.0, AssertInner::Failure(..))
+    }
+
+    /// Check whether this assertion succeeded
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        matches!(self.0, AssertInner::Success)
+    }
+}
+
+impl Drop for Assert {
+    fn drop(&mut self) {
+        if let AssertInner::Failure(loc, msg, trail) = &self.0 {
+            if std::thread::panicking() && DEFUSE_ON_PANIC.with(Cell::get) {
+                eprintln!("{}", render_failure(loc, msg, trail, Sink::DropWhilePanicking));
+            } else {
+                panic!("{}", render_failure(loc, msg, trail, Sink::Drop));
+            }
+        }
+    }
+}
+
+/// Invert an assertion. A failed assertion becomes a success, and a successful assertion becomes
+/// a failure at the caller's location.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::assert::Assert;
+/// // Calling `not` as a method requires the trait in scope
+/// use core::ops::Not;
+///
+/// fn check(a: u32, b: u32) -> Assert {
+///     (!Assert::eq(&a, &b))?;
+///     Assert::eq(&a, &b).not()
+/// }
+/// # check(1, 2).to_panic();
+/// ```
+impl Not for Assert {
+    type Output = Assert;
+
+    #[track_caller]
+    fn not(self) -> Self::Output {
+        match self.inner_defuse() {
+            AssertInner::Failure(..) => Assert::success(),
+            AssertInner::Success => Assert::failure().msg("Expected assertion to fail"),
+        }
+    }
+}
+
+impl Debug for Assert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Assert {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match &self.0 {
+            AssertInner::Failure(loc, msg, trail) => {
+                write!(f, "Assertion Failed: {msg} at {loc}{}", render_trail(trail))
+            }
+            AssertInner::Success => {
+                write!(f, "Assertion Successful")
+            }
+        }
+    }
+}
+
+impl Try for Assert {
+    type Output = ();
+    type Residual = AssertResidual;
+
+    fn from_output(_: Self::Output) -> Self {
+        Assert::success()
+    }
+
+    fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+        match self.inner_defuse() {
+            AssertInner::Failure(loc, msg, trail) => {
+                ControlFlow::Break(AssertResidual(loc, msg, trail))
+            }
+            AssertInner::Success => ControlFlow::Continue(()),
+        }
+    }
+}
+
+impl FromResidual for Assert {
+    fn from_residual(residual: <Self as Try>::Residual) -> Self {
+        Assert(AssertInner::Failure(residual.0, residual.1, residual.2))
+    }
+}
+
+impl<E> FromResidual<Result<Infallible, E>> for Assert
+where
+    E: Error,
+{
+    #[track_caller]
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Ok(val) => match val {},
+            Err(err) => Assert::failure().msg(err.to_string())
+        }
+    }
+}
+
+impl FromResidual<Option<Infallible>> for Assert {
+    #[track_caller]
+    fn from_residual(residual: Option<Infallible>) -> Self {
+        match residual {
+            Some(val) => match val {},
+            None => Assert::failure().msg("Expected `Option::Some`, got `Option::None`"),
+        }
+    }
+}
+
+impl Termination for Assert {
     fn report(self) -> ExitCode {
-        if let AssertInner::Failure(_, _) = self.0 {
-            println!("{self:?}");
+        if let AssertInner::Failure(loc, msg, trail) = self.inner_defuse() {
+            println!("{}", render_failure(loc, &msg, &trail, Sink::Report));
             ExitCode::FAILURE
         } else {
             ExitCode::SUCCESS
         }
     }
-}
+}
+
+impl<E> From<E> for Assert
+where
+    E: Error,
+{
+    fn from(err: E) -> Self {
+        Assert::failure().msg(err.to_string())
+    }
+}
+
+impl<T> From<Assert> for Result<T, Assert> {
+    fn from(a: Assert) -> Self {
+        Err(a)
+    }
+}
+
+impl<T> FromResidual<AssertResidual> for Result<T, Assert> {
+    fn from_residual(residual: AssertResidual) -> Self {
+        Err(Assert::from_residual(residual))
+    }
+}
+
+/// Evaluate several assertions in order, stopping at the first failure. Evaluates to that failed
+/// assertion, or a successful one if none failed.
+///
+/// This short-circuits - once an assertion has failed, the remaining expressions are not
+/// evaluated at all, so no further assertions are created that would need to be consumed.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::assert::Assert;
+/// # use trying::assert_all;
+/// fn check(a: u32, b: u32) -> Assert {
+///     assert_all!(
+///         Assert::ne(&a, &0),
+///         Assert::ne(&b, &0),
+///         Assert::eq(&(a % b), &0),
+///     )
+/// }
+/// # check(4, 2).to_panic();
+/// ```
+#[macro_export]
+macro_rules! assert_all {
+    ($($a:expr),* $(,)?) => {{
+        let mut out = $crate::assert::Assert::success();
+        $(
+            if out.is_success() {
+                out = $a;
+            }
+        )*
+        out
+    }};
+}
+
+/// Assert that a boolean expression is true, producing an [`Assert`] instead of panicking. An
+/// optional format message replaces the default failure message.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::assert::Assert;
+/// # use trying::try_assert;
+/// fn check(a: u32) -> Assert {
+///     try_assert!(a.is_power_of_two(), "{a} is not a power of two")
+/// }
+/// # check(4).to_panic();
+/// ```
+#[macro_export]
+macro_rules! try_assert {
+    ($cond:expr $(,)?) => {
+        $crate::assert::Assert::is_true($cond)
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        $crate::assert::Assert::is_true($cond).with_msg(|| ::std::format!($($arg)+))
+    };
+}
+
+/// Assert that a boolean expression is true, producing an [`Assert`] which reports the source of
+/// the expression on failure, as `Assertion failed: {expr}`. An optional format message is
+/// appended to the failure message.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::assert::Assert;
+/// # use trying::assert_expr;
+/// fn check(a: u32, b: u32) -> Assert {
+///     assert_expr!(a % b == 0, "with a = {a}, b = {b}")
+/// }
+/// # check(4, 2).to_panic();
+/// ```
+#[macro_export]
+macro_rules! assert_expr {
+    ($cond:expr $(,)?) => {
+        $crate::assert::Assert::succeed_if(
+            $cond,
+            ::core::concat!("Assertion failed: ", ::core::stringify!($cond)),
+        )
+    };
+    ($cond:expr, $($arg:tt)+) => {
+        $crate::assert::Assert::is_true($cond).with_msg(|| {
+            ::std::format!(
+                "Assertion failed: {}: {}",
+                ::core::stringify!($cond),
+                ::core::format_args!($($arg)+)
+            )
+        })
+    };
+}
+
+/// Assert that two expressions are equal, producing an [`Assert`] instead of panicking. An
+/// optional format message is appended to the default failure message, like `assert_eq!`.
+#[macro_export]
+macro_rules! try_assert_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::assert::Assert::eq(left, right),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                $crate::assert::Assert::eq(left, right)
+                    .__append_msg(|| ::std::format!($($arg)+))
+            }
+        }
+    };
+}
+
+/// Assert that two expressions are not equal, producing an [`Assert`] instead of panicking. An
+/// optional format message is appended to the default failure message, like `assert_ne!`.
+#[macro_export]
+macro_rules! try_assert_ne {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => $crate::assert::Assert::ne(left, right),
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                $crate::assert::Assert::ne(left, right)
+                    .__append_msg(|| ::std::format!($($arg)+))
+            }
+        }
+    };
+}
+
+/// Consume a list of assertions, combining them into one. If any of them failed, the result is a
+/// failure at the caller's location, with a message listing the location and message of every
+/// failed assertion on separate lines.
+#[track_caller]
+pub fn collect(asserts: Vec<Assert>) -> Assert {
+    let failures = asserts
+        .into_iter()
+        .filter_map(|a| match a.inner_defuse() {
+            AssertInner::Failure(loc, msg, trail) => {
+                Some(format!("{msg} at {loc}{}", render_trail(&trail)))
+            }
+            AssertInner::Success => None,
+        })
+        .collect::<Vec<_>>();
+
+    if failures.is_empty() {
+        Assert::success()
+    } else {
+        Assert::failure().msg(format!(
+            "{} assertions failed:\n{}",
+            failures.len(),
+            failures.join("\n")
+        ))
+    }
+}
+
+/// Create a new [`AssertGroup`], which will prefix the messages of failed assertions with `prefix`
+pub fn group(prefix: &str) -> AssertGroup {
+    AssertGroup {
+        prefix: prefix.to_owned(),
+    }
+}
+
+/// A group of related assertions, sharing a common message prefix. Created with [`group`].
+///
+/// # Examples
+///
+/// ```
+/// # use trying::assert::{self, Assert};
+/// # struct User { id: u32, name: &'static str }
+/// fn check_user(u: &User) -> Assert {
+///     let g = assert::group("user");
+///
+///     g.check(Assert::eq(&u.id, &1))?;
+///     g.check(Assert::eq(&u.name, &"Alice"))
+/// }
+/// # check_user(&User { id: 1, name: "Alice" }).to_panic();
+/// ```
+pub struct AssertGroup {
+    prefix: String,
+}
+
+impl AssertGroup {
+    /// Prefix the message of an assertion with the prefix of this group, if it failed. The
+    /// location of the assertion is unchanged.
+    pub fn check(&self, a: Assert) -> Assert {
+        Assert(match a.inner_defuse() {
+            AssertInner::Failure(loc, msg, trail) => {
+                AssertInner::Failure(loc, Cow::from(format!("{}: {msg}", self.prefix)), trail)
+            }
+            AssertInner::Success => AssertInner::Success,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_success_const() -> Assert {
+        const OK: fn() -> Assert = Assert::success;
+        static SUCCESS: Assert = Assert::success();
+        const IS_SUCCESS: bool = SUCCESS.is_success();
+
+        Assert::is_true(IS_SUCCESS)?;
+        OK()
+    }
+
+    #[test]
+    fn test_assert_true() -> Assert {
+        Assert::is_true(true)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_true_failure() {
+        Assert::is_true(false).to_panic()
+    }
+
+    #[test]
+    fn test_assert_false() -> Assert {
+        Assert::is_false(false)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_false_failure() {
+        Assert::is_false(true).to_panic()
+    }
+
+    #[test]
+    fn test_assert_fail_if() -> Assert {
+        Assert::fail_if(false, "[Custom Message]")
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message]"]
+    fn test_assert_fail_if_failure() {
+        Assert::fail_if(true, "[Custom Message]").to_panic()
+    }
+
+    #[test]
+    fn test_assert_succeed_if() -> Assert {
+        Assert::succeed_if(true, "[Custom Message]")
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message]"]
+    fn test_assert_succeed_if_failure() {
+        Assert::succeed_if(false, "[Custom Message]").to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `\"a\"` to equal `\"b\"`"]
+    fn test_assert_eq_borrow_failure() {
+        Assert::eq_borrow::<_, _, str>(&String::from("a"), &"b").to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `Id` to equal `Id`. Both are formatted the same, but have \
+                      types `trying::assert::tests::test_assert_eq_type_names::a::Id` and \
+                      `trying::assert::tests::test_assert_eq_type_names::b::Id`"]
+    fn test_assert_eq_type_names() {
+        mod a {
+            #[derive(Debug)]
+            pub struct Id;
+        }
+        mod b {
+            #[derive(Debug)]
+            pub struct Id;
+        }
+        impl PartialEq<b::Id> for a::Id {
+            fn eq(&self, _: &b::Id) -> bool {
+                false
+            }
+        }
+
+        Assert::eq(&a::Id, &b::Id).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `1` to equal `2` at"]
+    fn test_assert_eq_no_type_names() {
+        Assert::eq(&1, &2).to_panic()
+    }
+
+    #[test]
+    fn test_assert_eq_lazy() -> Assert {
+        struct NoDebug(i32);
+        impl PartialEq for NoDebug {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        Assert::eq_lazy(&NoDebug(1), &NoDebug(1), || unreachable!())
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message] 2"]
+    fn test_assert_eq_lazy_failure() {
+        Assert::eq_lazy(&1, &2, || format!("[Custom Message] {}", 2)).to_panic()
+    }
+
+    #[test]
+    fn test_assert_all_eq() -> Assert {
+        Assert::all_eq(&[(1, 1), (2, 2)])?;
+        Assert::all_eq_iter([1, 2, 3].iter().map(|x| (x * 2, x + x)))
+    }
+
+    #[test]
+    #[should_panic = "Expected `3` to equal `4` at index 1"]
+    fn test_assert_all_eq_failure() {
+        Assert::all_eq(&[(1, 1), (3, 4), (5, 6)]).to_panic()
+    }
+
+    #[test]
+    fn test_assert_iter_eq() -> Assert {
+        Assert::iter_eq((1..4).map(|x| x * 2), [2, 4, 6])
+    }
+
+    #[test]
+    #[should_panic = "Expected `4` to equal `5` at index 1"]
+    fn test_assert_iter_eq_failure() {
+        Assert::iter_eq((1..4).map(|x| x * 2), [2, 5, 6]).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "left ended at index 3 while right yielded `8`"]
+    fn test_assert_iter_eq_short() {
+        Assert::iter_eq((1..4).map(|x| x * 2), [2, 4, 6, 8]).to_panic()
+    }
+
+    #[test]
+    fn test_assert_eq_shallow() -> Assert {
+        Assert::eq_shallow(&vec![1; 100], &vec![1; 100], 10)
+    }
+
+    #[test]
+    #[should_panic = "Expected `[1, 2, 3...` to equal `[1, 2, 4...` (truncated to 8 characters)"]
+    fn test_assert_eq_shallow_failure() {
+        Assert::eq_shallow(&[1, 2, 3, 4, 5], &[1, 2, 4, 5, 6], 8).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `[1, 2]` to equal `[1, 3]` at"]
+    fn test_assert_eq_shallow_short() {
+        Assert::eq_shallow(&[1, 2], &[1, 3], 8).to_panic()
+    }
+
+    #[test]
+    fn test_assert_check() -> Assert {
+        Assert::check(&16u32, |n| n.is_power_of_two(), "be a power of two")
+    }
+
+    #[test]
+    #[should_panic = "Expected `12` to satisfy be a power of two"]
+    fn test_assert_check_failure() {
+        Assert::check(&12u32, |n| n.is_power_of_two(), "be a power of two").to_panic()
+    }
+
+    #[test]
+    fn test_assert_from_result() -> Assert {
+        Assert::from_result(Ok::<_, &str>(1))
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message]"]
+    fn test_assert_from_result_failure() {
+        Assert::from_result(Err::<(), _>("[Custom Message]")).to_panic()
+    }
+
+    #[test]
+    fn test_assert_eq() -> Assert {
+        Assert::eq(&1u32, &1u32)
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_eq_failure() {
+        Assert::eq(&"hello", &"world").to_panic()
+    }
 
-impl<E> From<E> for Assert
-where
-    E: Error,
-{
-    fn from(err: E) -> Self {
-        Assert::failure().msg(err.to_string())
+    #[test]
+    fn test_assert_ne() -> Assert {
+        Assert::ne(&1u32, &2u32)
     }
-}
 
-impl<T> From<Assert> for Result<T, Assert> {
-    fn from(a: Assert) -> Self {
-        Err(a)
+    #[test]
+    #[should_panic]
+    fn test_assert_ne_failure() {
+        Assert::ne(&1.0, &1.0).to_panic()
     }
-}
 
-impl<T> FromResidual<AssertResidual> for Result<T, Assert> {
-    fn from_residual(residual: AssertResidual) -> Self {
-        Err(Assert::from_residual(residual))
+    #[test]
+    #[should_panic = "NaN is never equal to anything"]
+    fn test_assert_eq_nan() {
+        Assert::eq(&f32::NAN, &f32::NAN).to_panic()
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    #[should_panic = "NaN is never equal to anything"]
+    fn test_assert_eq_nan_nested() {
+        Assert::eq(&vec![1.0, f64::NAN], &vec![1.0, f64::NAN]).to_panic()
+    }
 
     #[test]
-    fn test_assert_true() -> Assert {
-        Assert::is_true(true)
+    fn test_assert_nan_hint_only_floats() {
+        #[derive(Debug, PartialEq)]
+        enum K {
+            NaN,
+            Inf,
+        }
+
+        let a = Assert::eq(&K::NaN, &K::Inf);
+        assert!(!a.to_string().contains("NaN is never equal"), "{a}");
+        a.defuse();
+
+        let a = Assert::eq(&("NaN", 1.0f64), &("x", 2.0));
+        assert!(!a.to_string().contains("NaN is never equal"), "{a}");
+        a.defuse();
+
+        let a = Assert::eq(&("\"NaN", f64::NAN), &("x", 2.0));
+        assert!(a.to_string().contains("NaN is never equal"), "{a}");
+        a.defuse();
     }
 
     #[test]
-    #[should_panic]
-    fn test_assert_true_failure() {
-        Assert::is_true(false).to_panic()
+    fn test_assert_eq_msg() -> Assert {
+        Assert::eq_msg(&1u32, &1u32, "[Custom Message]")
     }
 
     #[test]
-    fn test_assert_false() -> Assert {
-        Assert::is_false(false)
+    #[should_panic = "[Custom Message]"]
+    fn test_assert_eq_msg_failure() {
+        Assert::eq_msg(&1u32, &2u32, "[Custom Message]").to_panic()
+    }
+
+    #[test]
+    fn test_assert_eq_with() -> Assert {
+        Assert::eq_with(&"Hello", &"hello", |a, b| a.eq_ignore_ascii_case(b))
     }
 
     #[test]
     #[should_panic]
-    fn test_assert_false_failure() {
-        Assert::is_false(true).to_panic()
+    fn test_assert_eq_with_failure() {
+        Assert::eq_with(&"Hello", &"world", |a, b| a.eq_ignore_ascii_case(b)).to_panic()
     }
 
     #[test]
-    fn test_assert_eq() -> Assert {
-        Assert::eq(&1u32, &1u32)
+    fn test_assert_ne_with() -> Assert {
+        Assert::ne_with(&"Hello", &"world", |a, b| a.eq_ignore_ascii_case(b))
     }
 
     #[test]
     #[should_panic]
-    fn test_assert_eq_failure() {
-        Assert::eq(&"hello", &"world").to_panic()
+    fn test_assert_ne_with_failure() {
+        Assert::ne_with(&"Hello", &"hello", |a, b| a.eq_ignore_ascii_case(b)).to_panic()
     }
 
     #[test]
-    fn test_assert_ne() -> Assert {
-        Assert::ne(&1u32, &2u32)
+    fn test_assert_is_sorted() -> Assert {
+        Assert::is_sorted(&[1, 2, 2, 3])?;
+        Assert::is_sorted_by(&[3, 2, 1], |a, b| a >= b)
+    }
+
+    #[test]
+    #[should_panic = "`3` at index 1 is out of order with `2` at index 2"]
+    fn test_assert_is_sorted_failure() {
+        Assert::is_sorted(&[1, 3, 2]).to_panic()
+    }
+
+    #[test]
+    fn test_assert_eq_unordered() -> Assert {
+        Assert::eq_unordered(&[1, 2, 2, 3], &[2, 3, 1, 2])?;
+        Assert::eq_unordered_hash(&[1, 2, 2, 3], &[2, 3, 1, 2])
+    }
+
+    #[test]
+    #[should_panic = "Only in left: `[2]`, only in right: `[4]`"]
+    fn test_assert_eq_unordered_failure() {
+        Assert::eq_unordered(&[1, 2, 2, 3], &[2, 3, 1, 4]).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Only in left: `[2]`, only in right: `[4]`"]
+    fn test_assert_eq_unordered_hash_failure() {
+        Assert::eq_unordered_hash(&[1, 2, 2, 3], &[2, 3, 1, 4]).to_panic()
+    }
+
+    #[test]
+    fn test_assert_slices_approx_eq() -> Assert {
+        Assert::slices_approx_eq(&[1.0, 2.0], &[1.05, 1.95], 0.1)?;
+        Assert::slices_approx_eq_f32(&[1.0, 2.0], &[1.05, 1.95], 0.1)?;
+        Assert::slices_approx_eq(&[f64::INFINITY], &[f64::INFINITY], 0.1)?;
+        Assert::slices_approx_eq_f32(&[f32::NEG_INFINITY], &[f32::NEG_INFINITY], 0.1)
+    }
+
+    #[test]
+    #[should_panic = "at index 1"]
+    fn test_assert_slices_approx_eq_failure() {
+        Assert::slices_approx_eq(&[1.0, 2.0], &[1.0, 3.0], 0.1).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `NaN` to approximately equal `NaN` at index 0"]
+    fn test_assert_slices_approx_eq_nan_failure() {
+        Assert::slices_approx_eq_f32(&[f32::NAN], &[f32::NAN], 0.1).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "equal length"]
+    fn test_assert_slices_approx_eq_len_failure() {
+        Assert::slices_approx_eq_f32(&[1.0, 2.0], &[1.0], 0.1).to_panic()
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    fn test_assert_matches_regex() -> Assert {
+        Assert::matches_regex("version 1.2.3", r"\d+\.\d+\.\d+")
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic = "to match regex"]
+    fn test_assert_matches_regex_failure() {
+        Assert::matches_regex("version 1.2", r"\d+\.\d+\.\d+").to_panic()
+    }
+
+    #[test]
+    #[cfg(feature = "regex")]
+    #[should_panic = "Invalid regex"]
+    fn test_assert_matches_regex_invalid() {
+        Assert::matches_regex("version 1.2", r"(").to_panic()
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_assert_json_eq() -> Assert {
+        let a = serde_json::json!({"a": 1, "b": [1, 2]});
+        let b = serde_json::from_str(r#"{"b": [1, 2], "a": 1}"#).unwrap();
+        Assert::json_eq(&a, &b)
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    #[should_panic = "first difference at `$.b[1].c`"]
+    fn test_assert_json_eq_failure() {
+        let a = serde_json::json!({"a": 1, "b": [1, {"c": true}]});
+        let b = serde_json::json!({"a": 1, "b": [1, {"c": false}]});
+        Assert::json_eq(&a, &b).to_panic()
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    #[should_panic = "first difference at `$.b[2]`"]
+    fn test_assert_json_eq_length() {
+        let a = serde_json::json!({"a": 1, "b": [1, 2]});
+        let b = serde_json::json!({"a": 1, "b": [1, 2, 3]});
+        Assert::json_eq(&a, &b).to_panic()
+    }
+
+    #[derive(Debug)]
+    struct Wrapper(core::num::ParseIntError);
+
+    impl Display for Wrapper {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            write!(f, "wrapped")
+        }
+    }
+
+    impl Error for Wrapper {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn test_assert_err_is() -> Assert {
+        let res: Result<i32, Box<dyn Error>> = "a".parse::<i32>().map_err(Box::from);
+        Assert::err_is::<core::num::ParseIntError>(&res)
+    }
+
+    #[test]
+    #[should_panic = "ParseFloatError`, got `ParseIntError"]
+    fn test_assert_err_is_failure() {
+        let res: Result<i32, Box<dyn Error>> = "a".parse::<i32>().map_err(Box::from);
+        Assert::err_is::<core::num::ParseFloatError>(&res).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "ParseIntError`, got `Ok(1)`"]
+    fn test_assert_err_is_ok() {
+        let res: Result<i32, Box<dyn Error>> = Ok(1);
+        Assert::err_is::<core::num::ParseIntError>(&res).to_panic()
+    }
+
+    #[test]
+    fn test_assert_source_is() -> Assert {
+        let err = Wrapper("a".parse::<i32>().unwrap_err());
+        Assert::source_is::<Wrapper>(&err)?;
+        Assert::source_is::<core::num::ParseIntError>(&err)
+    }
+
+    #[test]
+    #[should_panic = "or one of its sources to be of type"]
+    fn test_assert_source_is_failure() {
+        let err = Wrapper("a".parse::<i32>().unwrap_err());
+        Assert::source_is::<core::num::ParseFloatError>(&err).to_panic()
+    }
+
+    #[test]
+    fn test_assert_ptr_eq() -> Assert {
+        let a = 1;
+        Assert::ref_eq(&a, &a)
     }
 
     #[test]
     #[should_panic]
-    fn test_assert_ne_failure() {
-        Assert::ne(&1.0, &1.0).to_panic()
+    fn test_assert_ptr_eq_failure() {
+        let (a, b) = (1, 1);
+        Assert::ref_eq(&a, &b).to_panic()
+    }
+
+    #[test]
+    fn test_assert_catch() -> Assert {
+        Assert::catch(|| 1)?;
+        Assert::catch_panic(|| panic!("[Custom Message]"))
+    }
+
+    #[test]
+    #[should_panic = "Expected no panic, but panicked with `[Custom Message]`"]
+    fn test_assert_catch_failure() {
+        Assert::catch(|| panic!("[Custom Message]")).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected a panic, but none occurred"]
+    fn test_assert_catch_panic_failure() {
+        Assert::catch_panic(|| 1).to_panic()
+    }
+
+    #[test]
+    fn test_assert_within() -> Assert {
+        Assert::within(Duration::from_secs(10), || ())
+    }
+
+    #[test]
+    #[should_panic = "Expected to complete within `1ms`, but took"]
+    fn test_assert_within_failure() {
+        Assert::within(Duration::from_millis(1), || {
+            std::thread::sleep(Duration::from_millis(10));
+        })
+        .to_panic()
+    }
+
+    #[test]
+    fn test_assert_panics_with() -> Assert {
+        Assert::panics_with(|| panic!("[Custom Message] {}", 1), "Message] 1")
+    }
+
+    #[test]
+    #[should_panic = "Expected a panic containing `other`, but panicked with `[Custom Message]`"]
+    fn test_assert_panics_with_mismatch() {
+        Assert::panics_with(|| panic!("[Custom Message]"), "other").to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected a panic containing `other`, but none occurred"]
+    fn test_assert_panics_with_no_panic() {
+        Assert::panics_with(|| (), "other").to_panic()
+    }
+
+    #[test]
+    fn test_assert_duration_approx_eq() -> Assert {
+        let (a, b) = (Duration::from_millis(100), Duration::from_millis(96));
+        Assert::duration_approx_eq(a, b, Duration::from_millis(5))?;
+        Assert::duration_approx_eq(b, a, Duration::from_millis(5))
+    }
+
+    #[test]
+    #[should_panic = "Expected `100ms` to be within `5ms` of `90ms`, but they differ by `10ms`"]
+    fn test_assert_duration_approx_eq_failure() {
+        let tolerance = Duration::from_millis(5);
+        Assert::duration_approx_eq(Duration::from_millis(100), Duration::from_millis(90), tolerance)
+            .to_panic()
+    }
+
+    #[test]
+    fn test_assert_not() -> Assert {
+        !Assert::eq(&1, &2)
+    }
+
+    #[test]
+    #[should_panic = "Expected assertion to fail"]
+    fn test_assert_not_failure() {
+        Assert::eq(&1, &1).not().to_panic()
+    }
+
+    #[test]
+    fn test_assert_not_try() {
+        fn check() -> Assert {
+            Assert::eq(&1, &1).not()?;
+            Assert::success()
+        }
+
+        let a = check();
+        assert!(a.to_string().starts_with("Assertion Failed: Expected assertion to fail at "));
+        a.defuse();
     }
 
     #[test]
@@ -364,4 +1941,240 @@ mod tests {
     fn test_assert_with_msg() {
         Assert::failure().with_msg(|| String::from("[Custom Message]")).to_panic()
     }
+
+    #[test]
+    #[should_panic = "[Custom Message 1]"]
+    fn test_assert_msg_display() {
+        Assert::failure().msg_display(format_args!("[Custom Message {}]", 1)).to_panic()
+    }
+
+    #[test]
+    fn test_defuse_on_panic() {
+        defuse_on_panic(true);
+        let res = std::panic::catch_unwind(|| {
+            let _a = Assert::failure();
+            panic!("Inner panic");
+        });
+        defuse_on_panic(false);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn test_to_json() {
+        assert_eq!(Assert::success().to_json(), None);
+
+        let a = Assert::failure().msg("[Custom \"Message\"]");
+        let json: serde_json::Value = serde_json::from_str(&a.to_json().unwrap()).unwrap();
+        a.defuse();
+        assert_eq!(json["message"], "[Custom \"Message\"]");
+        assert_eq!(json["location"]["file"], file!());
+    }
+
+    #[test]
+    fn test_display() {
+        let a = Assert::failure().msg("[Custom Message]");
+        assert!(a.to_string().starts_with("Assertion Failed: [Custom Message] at "));
+        a.defuse();
+
+        assert_eq!(Assert::success().to_string(), "Assertion Successful");
+    }
+
+    #[test]
+    fn test_custom_formatter() {
+        fn fmt(loc: &Location<'_>, msg: &str) -> String {
+            format!("{{\"message\":{msg:?},\"line\":{}}}", loc.line())
+        }
+
+        TEST_FORMATTER.with(|f| f.set(Some(fmt)));
+
+        let (a, line) = (Assert::eq(&1, &2), line!());
+        let expected = format!("{{\"message\":\"Expected `1` to equal `2`\",\"line\":{line}}}");
+        let payload = catch_unwind(|| a.to_panic()).unwrap_err();
+        assert_eq!(panic_msg(&*payload), expected);
+
+        let (a, line) = (Assert::eq(&1, &2), line!());
+        let expected = format!(
+            "Failed assertion dropped. (Did you forget a `?` or `to_panic`?)\n\
+             {{\"message\":\"Expected `1` to equal `2`\",\"line\":{line}}}"
+        );
+        let payload = catch_unwind(|| drop(a)).unwrap_err();
+        assert_eq!(panic_msg(&*payload), expected);
+
+        let loc = Location::caller();
+        let out = render_failure(loc, "Expected `1` to equal `2`", &[], Sink::Report);
+        assert_eq!(out, fmt(loc, "Expected `1` to equal `2`"));
+
+        TEST_FORMATTER.with(|f| f.set(None));
+    }
+
+    #[test]
+    fn test_assert_all() -> Assert {
+        crate::assert_all!(Assert::success(), Assert::is_true(true))
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message]"]
+    fn test_assert_all_failure() {
+        crate::assert_all!(
+            Assert::success(),
+            Assert::failure().msg("[Custom Message]"),
+            Assert::failure().msg("[Other Message]"),
+        )
+        .to_panic()
+    }
+
+    #[cfg(feature = "diff")]
+    #[test]
+    fn test_assert_eq_diff() -> Assert {
+        let a = Assert::eq(&vec![1, 2, 3], &vec![1, 4, 3]);
+        let msg = a.to_string();
+        a.defuse();
+
+        Assert::is_true(msg.contains("Expected `[1, 2, 3]` to equal `[1, 4, 3]`"))?;
+        Assert::is_true(msg.contains("\n-    2,\n+    4,\n"))
+    }
+
+    #[test]
+    fn test_try_assert() -> Assert {
+        crate::try_assert!(true)?;
+        crate::try_assert_eq!(1 + 1, 2)?;
+        crate::try_assert_ne!(1 + 1, 3, "custom {}", 1)
+    }
+
+    #[test]
+    #[should_panic = "Expected `2` to equal `3`"]
+    fn test_try_assert_eq_failure() {
+        crate::try_assert_eq!(1 + 1, 3).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Expected `2` to not equal `2`: [Custom Message] 1"]
+    fn test_try_assert_ne_msg_failure() {
+        crate::try_assert_ne!(1 + 1, 2, "[Custom Message] {}", 1).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "[Custom Message] 1"]
+    fn test_try_assert_failure() {
+        crate::try_assert!(false, "[Custom Message] {}", 1).to_panic()
+    }
+
+    #[test]
+    fn test_try_assert_location() -> Assert {
+        let (a, line) = (crate::try_assert_ne!(1, 1), line!());
+        let msg = format!("{a:?}");
+        a.defuse();
+        Assert::is_true(msg.contains(&format!("{}:{line}:", file!())))
+    }
+
+    #[test]
+    fn test_assert_expr() -> Assert {
+        let (a, b) = (1, 1);
+        crate::assert_expr!(a == b)?;
+        crate::assert_expr!(a == b, "custom {}", 1)
+    }
+
+    #[test]
+    #[should_panic = "Assertion failed: a + 1 == b"]
+    fn test_assert_expr_failure() {
+        let (a, b) = (1, 1);
+        crate::assert_expr!(a + 1 == b).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Assertion failed: a == b + 1: [Custom Message] 1"]
+    fn test_assert_expr_failure_msg() {
+        let (a, b) = (1, 1);
+        crate::assert_expr!(a == b + 1, "[Custom Message] {}", a).to_panic()
+    }
+
+    #[test]
+    #[should_panic = "Assertion failed: p == P { x: 2 }: [Custom Message] 1"]
+    fn test_assert_expr_failure_braces() {
+        #[derive(PartialEq)]
+        struct P {
+            x: i32,
+        }
+
+        let p = P { x: 1 };
+        crate::assert_expr!(p == P { x: 2 }, "[Custom Message] {}", p.x).to_panic()
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn test_colorize_operands() {
+        assert_eq!(
+            colorize_operands("Expected `1` to equal `2`"),
+            "Expected `\x1b[31m1\x1b[0m` to equal `\x1b[32m2\x1b[0m`"
+        );
+        assert_eq!(colorize_operands("[Custom Message]"), "[Custom Message]");
+    }
+
+    #[test]
+    #[cfg(feature = "color")]
+    fn test_render_failure_color() {
+        let loc = Location::caller();
+        let msg = "Expected `1` to equal `2`";
+
+        TEST_COLOR.with(|c| c.set(Some(true)));
+        let out = render_failure(loc, msg, &[], Sink::Panic);
+        let colored = "Expected `\x1b[31m1\x1b[0m` to equal `\x1b[32m2\x1b[0m`";
+        assert_eq!(out, format!("{colored} at {loc}"));
+
+        TEST_COLOR.with(|c| c.set(Some(false)));
+        let out = render_failure(loc, msg, &[], Sink::Panic);
+        assert_eq!(out, format!("{msg} at {loc}"));
+    }
+
+    #[test]
+    fn test_then_ret() {
+        assert_eq!(Assert::eq(&1, &1).then_ret("val").unwrap(), "val");
+
+        let res = Assert::eq(&1, &2).then_ret("val");
+        assert!(res.as_ref().is_err_and(Assert::is_failure));
+        res.unwrap_err().defuse();
+    }
+
+    #[test]
+    fn test_trace() -> Assert {
+        let (older, newer) = (Location::caller(), Location::caller());
+        let a = Assert::eq(&2, &1).trace(older).trace(newer);
+        let msg = a.to_string();
+        a.defuse();
+
+        Assert::is_true(msg.ends_with(&format!("\nFailure trail:\n  at {newer}\n  at {older}")))?;
+        Assert::success().trace_caller()
+    }
+
+    #[test]
+    fn test_collect() -> Assert {
+        collect(vec![Assert::success(), Assert::is_true(true)])
+    }
+
+    #[test]
+    fn test_collect_failure() -> Assert {
+        let a = collect(vec![
+            Assert::eq(&1, &2),
+            Assert::success(),
+            Assert::failure().msg("[Custom Message]"),
+        ]);
+        let msg = a.to_string();
+        a.defuse();
+
+        Assert::is_true(msg.contains("2 assertions failed"))?;
+        Assert::is_true(msg.contains("Expected `1` to equal `2`"))?;
+        Assert::is_true(msg.contains("[Custom Message]"))
+    }
+
+    #[test]
+    fn test_group() -> Assert {
+        group("group").check(Assert::success())
+    }
+
+    #[test]
+    #[should_panic = "group: Expected `1` to equal `2`"]
+    fn test_group_failure() {
+        group("group").check(Assert::eq(&1, &2)).to_panic()
+    }
 }
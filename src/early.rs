@@ -2,14 +2,23 @@
 //! generated early on.
 
 use core::convert::Infallible;
+#[cfg(feature = "futures")]
+use core::future::{self, Future};
 #[cfg(feature = "yeet")]
 use core::ops::Yeet;
-use core::ops::{ControlFlow, FromResidual, Try};
+use core::ops::{ControlFlow, Deref, DerefMut, FromResidual, Try};
+#[cfg(feature = "futures")]
+use core::task::{Context, Poll};
+use std::process::ExitCode;
 
+use crate::assert::Assert;
 use Early::*;
 
 /// An early-return value. A type for when a call may return a final result or want to continue
 /// execution.
+///
+/// The derived traits are only implemented when both payloads implement them, so `Early` and its
+/// `Try` implementation can be used with payloads that aren't `Debug`, `Clone`, or comparable.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Early<D, T> {
     /// The value to return if computation is done
@@ -45,6 +54,30 @@ impl<D, T> Early<D, T> {
         }
     }
 
+    /// Convert this `Early` into its `Done` value, or the default value of `D` if it is `Todo`
+    pub fn unwrap_or_default(self) -> D
+    where
+        D: Default,
+    {
+        if let Done(val) = self {
+            val
+        } else {
+            D::default()
+        }
+    }
+
+    /// Convert this `Early` into its `Todo` value, or the default value of `T` if it is `Done`
+    pub fn unwrap_todo_or_default(self) -> T
+    where
+        T: Default,
+    {
+        if let Todo(val) = self {
+            val
+        } else {
+            T::default()
+        }
+    }
+
     /// Get a new `Early` that holds references to the values in this `Early`
     pub fn as_ref(&self) -> Early<&D, &T> {
         match self {
@@ -61,6 +94,74 @@ impl<D, T> Early<D, T> {
         }
     }
 
+    /// Get a new `Early` that holds a reference to the `Done` value and a dereferenced `Todo`
+    /// value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::early::Early;
+    /// let e: Early<i32, String> = Early::Todo(String::from("hello"));
+    /// assert_eq!(e.as_deref(), Early::Todo("hello"));
+    /// ```
+    pub fn as_deref(&self) -> Early<&D, &T::Target>
+    where
+        T: Deref,
+    {
+        match self {
+            Done(val) => Done(val),
+            Todo(val) => Todo(val),
+        }
+    }
+
+    /// Get a new `Early` that holds a mutable reference to the `Done` value and a mutably
+    /// dereferenced `Todo` value
+    pub fn as_deref_mut(&mut self) -> Early<&mut D, &mut T::Target>
+    where
+        T: DerefMut,
+    {
+        match self {
+            Done(val) => Done(val),
+            Todo(val) => Todo(val),
+        }
+    }
+
+    /// Get a new `Early` that holds a dereferenced `Done` value and a reference to the `Todo`
+    /// value
+    pub fn as_deref_done(&self) -> Early<&D::Target, &T>
+    where
+        D: Deref,
+    {
+        match self {
+            Done(val) => Done(val),
+            Todo(val) => Todo(val),
+        }
+    }
+
+    /// Get a new `Early` that holds a mutably dereferenced `Done` value and a mutable reference to
+    /// the `Todo` value
+    pub fn as_deref_done_mut(&mut self) -> Early<&mut D::Target, &mut T>
+    where
+        D: DerefMut,
+    {
+        match self {
+            Done(val) => Done(val),
+            Todo(val) => Todo(val),
+        }
+    }
+
+    /// Transform the contained value, applying `on_done` if `Done` and `on_todo` if `Todo`
+    pub fn map<D2, T2>(
+        self,
+        on_done: impl FnOnce(D) -> D2,
+        on_todo: impl FnOnce(T) -> T2,
+    ) -> Early<D2, T2> {
+        match self {
+            Done(val) => Done(on_done(val)),
+            Todo(val) => Todo(on_todo(val)),
+        }
+    }
+
     /// If `Done`, return `Early::Done(D)`. If `Todo`, return `f(T)`
     pub fn and_then<U, F>(self, f: F) -> Early<D, U>
     where
@@ -71,6 +172,179 @@ impl<D, T> Early<D, T> {
             Todo(val) => f(val),
         }
     }
+
+    /// If `Done`, return `Early::Done(D)`. If `Todo`, return `other`
+    pub fn and<U>(self, other: Early<D, U>) -> Early<D, U> {
+        match self {
+            Done(val) => Done(val),
+            Todo(_) => other,
+        }
+    }
+
+    /// If `Done`, return `self`. If `Todo`, return `f()`, only calling `f` in that case
+    pub fn or_else(self, f: impl FnOnce() -> Early<D, T>) -> Early<D, T> {
+        match self {
+            Done(val) => Done(val),
+            Todo(_) => f(),
+        }
+    }
+
+    /// If `Done`, return `self`. If `Todo`, return `other`
+    pub fn or(self, other: Early<D, T>) -> Early<D, T> {
+        match self {
+            Done(val) => Done(val),
+            Todo(_) => other,
+        }
+    }
+
+    /// Create an `Early` from an `Option`, which is `Todo` if the `Option` is `Some`, or
+    /// `Done(done)` if it is `None`
+    pub fn from_option_todo(opt: Option<T>, done: D) -> Early<D, T> {
+        match opt {
+            Some(val) => Todo(val),
+            None => Done(done),
+        }
+    }
+
+    /// Create an `Early` from an `Option`, which is `Done` if the `Option` is `Some`, or
+    /// `Todo(todo)` if it is `None`
+    pub fn try_done(opt: Option<D>, todo: T) -> Early<D, T> {
+        match opt {
+            Some(val) => Done(val),
+            None => Todo(todo),
+        }
+    }
+
+    /// Create an `Early` by calling a fallible function, which is `Done` if it returns `Ok`, or
+    /// `Todo` if it returns `Err`
+    pub fn from_fn(f: impl FnOnce() -> Result<D, T>) -> Early<D, T> {
+        match f() {
+            Ok(val) => Done(val),
+            Err(val) => Todo(val),
+        }
+    }
+
+    /// Fold over the items of an iterator, starting with `init` and applying `f` to the current
+    /// accumulator and each item. If `f` returns `Done`, the fold stops and the `Done` value is
+    /// returned, otherwise the final accumulator is returned as `Todo`.
+    pub fn fold_todo<I>(iter: I, init: T, mut f: impl FnMut(T, I::Item) -> Early<D, T>) -> Self
+    where
+        I: IntoIterator,
+    {
+        let mut acc = init;
+        for item in iter {
+            acc = f(acc, item)?;
+        }
+        Todo(acc)
+    }
+
+    /// Convert this `Early` into a process exit code. `Done` is converted into the exit code, while
+    /// `Todo` is treated as [`ExitCode::SUCCESS`].
+    pub fn into_exit_code(self) -> ExitCode
+    where
+        D: Into<ExitCode>,
+    {
+        match self {
+            Done(val) => val.into(),
+            Todo(_) => ExitCode::SUCCESS,
+        }
+    }
+
+    /// Convert this `Early` into an assertion that it is `Done`, failing with `msg` if it is
+    /// still `Todo`
+    #[track_caller]
+    pub fn done_or_assert(self, msg: &str) -> Assert {
+        match self {
+            Done(_) => Assert::success(),
+            Todo(_) => Assert::failure().msg(msg.to_owned()),
+        }
+    }
+
+    /// Convert this `Early` into a [`ControlFlow`], with `Done` becoming `Break` and `Todo`
+    /// becoming `Continue`
+    pub fn into_control_flow(self) -> ControlFlow<D, T> {
+        match self {
+            Done(val) => ControlFlow::Break(val),
+            Todo(val) => ControlFlow::Continue(val),
+        }
+    }
+
+    /// Combine two `Early` values. If both are `Todo`, returns `Todo` with a tuple of both values,
+    /// otherwise returns the first `Done`.
+    pub fn zip<U>(self, other: Early<D, U>) -> Early<D, (T, U)> {
+        match (self, other) {
+            (Done(val), _) | (_, Done(val)) => Done(val),
+            (Todo(a), Todo(b)) => Todo((a, b)),
+        }
+    }
+}
+
+impl<D, T> Early<D, Early<D, T>> {
+    /// Convert an `Early<D, Early<D, T>>` into an `Early<D, T>`. This is `Done` if either level
+    /// is `Done`, with the outer `Done` taking precedence, and `Todo` only if both levels are
+    /// `Todo`.
+    pub fn flatten(self) -> Early<D, T> {
+        match self {
+            Done(val) | Todo(Done(val)) => Done(val),
+            Todo(Todo(val)) => Todo(val),
+        }
+    }
+}
+
+impl<D, T> Early<D, Option<T>> {
+    /// Convert an `Early<D, Option<T>>` into an `Option<Early<D, T>>`. `Todo(None)` becomes
+    /// `None`, while `Todo(Some(val))` and `Done(val)` are wrapped in `Some`.
+    pub fn transpose_option(self) -> Option<Early<D, T>> {
+        match self {
+            Done(val) => Some(Done(val)),
+            Todo(Some(val)) => Some(Todo(val)),
+            Todo(None) => None,
+        }
+    }
+}
+
+impl<D, T> Early<Option<D>, T> {
+    /// Convert an `Early<Option<D>, T>` into an `Option<Early<D, T>>`. `Done(None)` is treated as
+    /// a meaningful absence and becomes `None`, while `Done(Some(val))` and `Todo(val)` are
+    /// wrapped in `Some`.
+    pub fn transpose_done_option(self) -> Option<Early<D, T>> {
+        match self {
+            Done(Some(val)) => Some(Done(val)),
+            Done(None) => None,
+            Todo(val) => Some(Todo(val)),
+        }
+    }
+}
+
+/// Convert a [`ControlFlow`] into an `Early`, with `Break` becoming `Done` and `Continue` becoming
+/// `Todo`
+impl<D, T> From<ControlFlow<D, T>> for Early<D, T> {
+    fn from(flow: ControlFlow<D, T>) -> Self {
+        match flow {
+            ControlFlow::Break(val) => Done(val),
+            ControlFlow::Continue(val) => Todo(val),
+        }
+    }
+}
+
+/// Convert an `Option` into an `Early`, with `Some` becoming `Done` and `None` becoming `Todo`
+impl<D> From<Option<D>> for Early<D, ()> {
+    fn from(opt: Option<D>) -> Self {
+        match opt {
+            Some(val) => Done(val),
+            None => Todo(()),
+        }
+    }
+}
+
+/// Convert an `Early` into an `Option`, with `Done` becoming `Some` and `Todo` becoming `None`
+impl<D> From<Early<D, ()>> for Option<D> {
+    fn from(e: Early<D, ()>) -> Self {
+        match e {
+            Done(val) => Some(val),
+            Todo(()) => None,
+        }
+    }
 }
 
 impl<D, T> Try for Early<D, T> {
@@ -104,3 +378,381 @@ impl<D, T> FromResidual<Yeet<D>> for Early<D, T> {
         Done(residual.0)
     }
 }
+
+/// Collect an iterator of `Early` values into a single `Early`. Iteration stops at the first
+/// `Done`, which is returned. Otherwise, all the `Todo` values are collected and returned as
+/// `Todo`.
+impl<D, T, C> FromIterator<Early<D, T>> for Early<D, C>
+where
+    C: FromIterator<T>,
+{
+    fn from_iter<I: IntoIterator<Item = Early<D, T>>>(iter: I) -> Self {
+        let mut done = None;
+
+        let out = iter
+            .into_iter()
+            .scan(&mut done, |done, item| match item {
+                Done(val) => {
+                    **done = Some(val);
+                    None
+                }
+                Todo(val) => Some(val),
+            })
+            .collect();
+
+        match done {
+            Some(val) => Done(val),
+            None => Todo(out),
+        }
+    }
+}
+
+/// Pull `Early` values from an iterator, collecting the `Todo` values into a `Vec` until the first
+/// `Done`, which is returned immediately. See the [`FromIterator`] implementation on [`Early`] to
+/// collect into other types.
+pub fn scan_early<I, D, T>(iter: I) -> Early<D, Vec<T>>
+where
+    I: IntoIterator<Item = Early<D, T>>,
+{
+    iter.into_iter().collect()
+}
+
+/// Call `f` up to `max` times, passing the current attempt number starting from `0`. Returns the
+/// first `Done`, or the final `Todo` if `f` never finished.
+///
+/// # Panics
+///
+/// If `max` is `0`
+pub fn retry<D, T>(max: usize, mut f: impl FnMut(usize) -> Early<D, T>) -> Early<D, T> {
+    assert!(max > 0, "Called `retry` with `max` of 0");
+    let mut out = f(0);
+    for attempt in 1..max {
+        if let Done(_) = out {
+            break;
+        }
+        out = f(attempt);
+    }
+    out
+}
+
+/// An iterator over the `Todo` values of an iterator of `Early`, created by [`iter`]. Iteration
+/// stops at the first `Done`, which can then be retrieved with [`finish`](Self::finish).
+#[derive(Clone, Debug)]
+pub struct EarlyIter<I, D> {
+    iter: I,
+    done: Option<D>,
+}
+
+impl<I, D> EarlyIter<I, D> {
+    /// Consume the iterator, returning the `Done` value which stopped iteration, if any
+    pub fn finish(self) -> Option<D> {
+        self.done
+    }
+}
+
+impl<I, D, T> Iterator for EarlyIter<I, D>
+where
+    I: Iterator<Item = Early<D, T>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done.is_some() {
+            return None;
+        }
+        match self.iter.next()? {
+            Done(val) => {
+                self.done = Some(val);
+                None
+            }
+            Todo(val) => Some(val),
+        }
+    }
+}
+
+/// Adapt an iterator of `Early` into an iterator over its `Todo` values, stopping at the first
+/// `Done`. Use [`Iterator::by_ref`] to combine the adapter with other iterator methods while
+/// still being able to call [`EarlyIter::finish`] afterwards.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::early::{self, Early};
+/// let items = [Early::Todo(1), Early::Todo(2), Early::Done("stop"), Early::Todo(3)];
+/// let mut iter = early::iter(items);
+/// let doubled = iter.by_ref().map(|x| x * 2).collect::<Vec<_>>();
+/// assert_eq!(doubled, [2, 4]);
+/// assert_eq!(iter.finish(), Some("stop"));
+/// ```
+pub fn iter<I, D, T>(iter: I) -> EarlyIter<I::IntoIter, D>
+where
+    I: IntoIterator<Item = Early<D, T>>,
+{
+    EarlyIter {
+        iter: iter.into_iter(),
+        done: None,
+    }
+}
+
+/// Create a future from a polling function. `Done` completes the future with its value, while
+/// `Todo` is treated as [`Poll::Pending`], discarding the value. As with any future, `f` is
+/// responsible for arranging to be woken when it returns `Todo`.
+#[cfg(feature = "futures")]
+pub fn poll_fn_early<D, T>(
+    mut f: impl FnMut(&mut Context<'_>) -> Early<D, T>,
+) -> impl Future<Output = D> {
+    future::poll_fn(move |cx| match f(cx) {
+        Done(val) => Poll::Ready(val),
+        Todo(_) => Poll::Pending,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_both_done() {
+        let a: Early<i32, i32> = Done(1);
+        let b: Early<i32, i32> = Done(2);
+        assert_eq!(a.zip(b), Done(1));
+    }
+
+    #[test]
+    fn test_zip_one_done() {
+        let a: Early<i32, i32> = Todo(1);
+        let b: Early<i32, i32> = Done(2);
+        assert_eq!(a.zip(b), Done(2));
+        assert_eq!(b.zip(a), Done(2));
+    }
+
+    #[test]
+    fn test_zip_both_todo() {
+        let a: Early<i32, i32> = Todo(1);
+        let b: Early<i32, &str> = Todo("a");
+        assert_eq!(a.zip(b), Todo((1, "a")));
+    }
+
+    #[test]
+    fn test_from_option() {
+        assert_eq!(Early::from(Some(1)), Done(1));
+        assert_eq!(Early::<i32, _>::from(None), Todo(()));
+
+        assert_eq!(Option::from(Early::from(Some(1))), Some(1));
+        assert_eq!(Option::<i32>::from(Early::from(None)), None);
+    }
+
+    #[test]
+    fn test_from_option_todo() {
+        assert_eq!(Early::from_option_todo(Some(1), "done"), Todo(1));
+        assert_eq!(Early::<_, i32>::from_option_todo(None, "done"), Done("done"));
+    }
+
+    #[test]
+    fn test_scan_early() {
+        assert_eq!(scan_early::<_, &str, _>([Todo(1), Todo(2)]), Todo(vec![1, 2]));
+        assert_eq!(scan_early([Todo(1), Done("a"), Todo(2), Done("b")]), Done("a"));
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let out: Early<&str, String> = [Todo('a'), Todo('b')].into_iter().collect();
+        assert_eq!(out, Todo(String::from("ab")));
+
+        let mut iter = [Todo('a'), Done("a"), Todo('b')].into_iter();
+        let out: Early<&str, String> = iter.by_ref().collect();
+        assert_eq!(out, Done("a"));
+        assert_eq!(iter.next(), Some(Todo('b')));
+    }
+
+    #[test]
+    fn test_and() {
+        let a: Early<i32, i32> = Todo(1);
+        let b: Early<i32, &str> = Todo("a");
+        assert_eq!(a.and(b), Todo("a"));
+        assert_eq!(Done::<_, i32>(1).and(b), Done(1));
+        assert_eq!(a.and(Done::<_, &str>(2)), Done(2));
+    }
+
+    #[test]
+    fn test_or() {
+        let a: Early<i32, i32> = Todo(1);
+        assert_eq!(a.or(Todo(2)), Todo(2));
+        assert_eq!(a.or(Done(2)), Done(2));
+        assert_eq!(Done(1).or(Done(2)), Done::<_, i32>(1));
+    }
+
+    #[test]
+    fn test_into_exit_code() {
+        assert_eq!(Done::<_, ()>(ExitCode::FAILURE).into_exit_code(), ExitCode::FAILURE);
+        assert_eq!(Done::<_, ()>(2u8).into_exit_code(), ExitCode::from(2));
+        assert_eq!(Todo::<u8, _>(()).into_exit_code(), ExitCode::SUCCESS);
+    }
+
+    #[test]
+    fn test_retry() {
+        let mut polls = 0;
+        let out = retry(5, |attempt| {
+            polls += 1;
+            if attempt == 2 {
+                Done("ready")
+            } else {
+                Todo(attempt)
+            }
+        });
+        assert_eq!(out, Done("ready"));
+        assert_eq!(polls, 3);
+
+        assert_eq!(retry(2, Todo::<(), _>), Todo(1));
+    }
+
+    #[test]
+    fn test_fold_todo() {
+        let sum = |acc: i32, x: i32| if acc + x > 5 { Done(acc) } else { Todo(acc + x) };
+        assert_eq!(Early::fold_todo([1, 2], 0, sum), Todo(3));
+        assert_eq!(Early::fold_todo([1, 2, 3, 4], 0, sum), Done(3));
+    }
+
+    #[test]
+    fn test_done_or_assert() -> Assert {
+        Early::<_, ()>::Done(1).done_or_assert("not done")
+    }
+
+    #[test]
+    #[should_panic = "not done"]
+    fn test_done_or_assert_failure() {
+        Early::<(), _>::Todo(1).done_or_assert("not done").to_panic()
+    }
+
+    #[test]
+    fn test_unwrap_or_default() {
+        assert_eq!(Early::<i32, i32>::Done(1).unwrap_or_default(), 1);
+        assert_eq!(Early::<i32, i32>::Todo(1).unwrap_or_default(), 0);
+        assert_eq!(Early::<i32, i32>::Todo(1).unwrap_todo_or_default(), 1);
+        assert_eq!(Early::<i32, i32>::Done(1).unwrap_todo_or_default(), 0);
+    }
+
+    #[test]
+    fn test_map() {
+        let done: Early<i32, &str> = Done(1);
+        assert_eq!(done.map(|d| d + 1, str::len), Done(2));
+        let todo: Early<i32, &str> = Todo("abc");
+        assert_eq!(todo.map(|d| d + 1, str::len), Todo(3));
+    }
+
+    #[test]
+    #[cfg(feature = "futures")]
+    fn test_poll_fn_early() {
+        use core::pin::pin;
+        use core::task::Waker;
+
+        let mut count = 0;
+        let mut fut = pin!(poll_fn_early(|cx| {
+            count += 1;
+            if count == 3 {
+                Done(count)
+            } else {
+                cx.waker().wake_by_ref();
+                Todo(())
+            }
+        }));
+
+        let mut cx = Context::from_waker(Waker::noop());
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Pending);
+        assert_eq!(fut.as_mut().poll(&mut cx), Poll::Ready(3));
+    }
+
+    #[test]
+    fn test_control_flow() {
+        let done: Early<i32, &str> = Done(1);
+        assert_eq!(done.into_control_flow(), ControlFlow::Break(1));
+        assert_eq!(Early::from(done.into_control_flow()), done);
+
+        let todo: Early<i32, &str> = Todo("a");
+        assert_eq!(todo.into_control_flow(), ControlFlow::Continue("a"));
+        assert_eq!(Early::from(todo.into_control_flow()), todo);
+
+        let sum = [1, 2, 3, 4].iter().try_fold(0, |acc, x| {
+            Early::from_option_todo((acc < 3).then_some(acc + x), acc).into_control_flow()
+        });
+        assert_eq!(Early::from(sum), Done(3));
+    }
+
+    #[test]
+    fn test_transpose_option() {
+        assert_eq!(Early::<i32, Option<i32>>::Done(1).transpose_option(), Some(Done(1)));
+        assert_eq!(Early::<i32, Option<i32>>::Todo(Some(2)).transpose_option(), Some(Todo(2)));
+        assert_eq!(Early::<i32, Option<i32>>::Todo(None).transpose_option(), None);
+
+        assert_eq!(Early::<Option<i32>, i32>::Done(Some(1)).transpose_done_option(), Some(Done(1)));
+        assert_eq!(Early::<Option<i32>, i32>::Done(None).transpose_done_option(), None);
+        assert_eq!(Early::<Option<i32>, i32>::Todo(2).transpose_done_option(), Some(Todo(2)));
+    }
+
+    #[test]
+    fn test_from_fn() {
+        assert_eq!(Early::from_fn(|| "1".parse::<i32>()).unwrap(), 1);
+        assert!(matches!(Early::from_fn(|| "a".parse::<i32>()), Todo(_)));
+
+        assert_eq!(Early::try_done(Some(1), "todo"), Done(1));
+        assert_eq!(Early::<i32, _>::try_done(None, "todo"), Todo("todo"));
+    }
+
+    #[test]
+    fn test_iter() {
+        let steps = (1..10).map(|x| if x < 6 { Todo(x) } else { Done(x) });
+        let mut it = iter(steps);
+        let evens = it.by_ref().filter(|x| x % 2 == 0).map(|x| x * 10).collect::<Vec<_>>();
+        assert_eq!(evens, [20, 40]);
+        assert_eq!(it.next(), None);
+        assert_eq!(it.finish(), Some(6));
+
+        let mut it = iter([Early::<(), _>::Todo(1)]);
+        assert_eq!(it.by_ref().count(), 1);
+        assert_eq!(it.finish(), None);
+    }
+
+    #[test]
+    fn test_bare_payloads() {
+        struct Bare(i32);
+
+        fn step(x: i32) -> Early<Bare, Bare> {
+            if x > 1 {
+                Done(Bare(x))
+            } else {
+                Todo(Bare(x + 1))
+            }
+        }
+
+        fn run(x: i32) -> Early<Bare, Bare> {
+            let Bare(x) = step(x)?;
+            let Bare(x) = step(x)?;
+            Todo(Bare(x))
+        }
+
+        assert!(matches!(run(0), Todo(Bare(2))));
+        assert!(matches!(run(1), Done(Bare(2))));
+        assert!(matches!(step(0), Todo(Bare(1))));
+    }
+
+    #[test]
+    fn test_or_else() {
+        let done: Early<i32, i32> = Done(1);
+        assert_eq!(done.or_else(|| unreachable!()), Done(1));
+
+        let todo: Early<i32, i32> = Todo(1);
+        assert_eq!(todo.or_else(|| Done(2)), Done(2));
+        assert_eq!(todo.or_else(|| Todo(3)), Todo(3));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let val: Early<i32, Early<i32, i32>> = Done(1);
+        assert_eq!(val.flatten(), Done(1));
+        let val: Early<i32, Early<i32, i32>> = Todo(Done(2));
+        assert_eq!(val.flatten(), Done(2));
+        let val: Early<i32, Early<i32, i32>> = Todo(Todo(3));
+        assert_eq!(val.flatten(), Todo(3));
+    }
+}
@@ -2,7 +2,8 @@
 
 use core::convert::Infallible;
 use core::fmt::Debug;
-use core::ops::{ControlFlow, FromResidual, Try};
+use core::ops::{ControlFlow, Deref, DerefMut, FromResidual, Try};
+use core::{mem, ptr};
 use core::result::Result as CoreResult;
 use std::io;
 use std::io::Write;
@@ -14,15 +15,51 @@ use core::ops::Yeet;
 mod maybe_warn;
 
 use self::Result::*;
+use crate::early::Early;
 pub use maybe_warn::MaybeWarn;
 
-#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+/// A result which may carry a warning alongside its value.
+///
+/// Results are ordered by variant first, with `Ok < Warn < Err`, then by their contents. Two
+/// `Warn` values are compared by value, then by warning.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Result<T, E, W = E> {
     Ok(T),
     Warn(T, W),
     Err(E),
 }
 
+/// The diagnostic half of a [`Result`], as produced by [`Result::split`]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Diag<E, W = E> {
+    /// A warning, which was attached to a value
+    Warn(W),
+    /// An error, which replaced the value
+    Err(E),
+}
+
+/// All the errors and warnings from a batch of results, as produced by
+/// [`Result::collect_diagnostics`]
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Diagnostics<E, W = E> {
+    /// The errors, in iteration order
+    pub errors: Vec<E>,
+    /// The warnings, in iteration order
+    pub warnings: Vec<W>,
+}
+
+impl<E, W> Diagnostics<E, W> {
+    /// Check whether there are no errors or warnings
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+
+    /// Check whether there are any errors
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+}
+
 impl<T, E, W> Result<T, E, W> {
     #[inline]
     pub fn is_ok(&self) -> bool {
@@ -39,6 +76,255 @@ impl<T, E, W> Result<T, E, W> {
         matches!(self, Err(_))
     }
 
+    /// Check whether this result is `Ok` or `Warn` with a value equal to `x`
+    #[inline]
+    pub fn contains<U>(&self, x: &U) -> bool
+    where
+        U: PartialEq<T>,
+    {
+        match self {
+            Ok(val) | Warn(val, _) => x == val,
+            Err(_) => false,
+        }
+    }
+
+    /// Check whether this result is `Err` with an error equal to `e`
+    #[inline]
+    pub fn contains_err<F>(&self, e: &F) -> bool
+    where
+        F: PartialEq<E>,
+    {
+        match self {
+            Err(err) => e == err,
+            Ok(_) | Warn(_, _) => false,
+        }
+    }
+
+    /// Check whether this result is `Warn` with a warning equal to `w`
+    #[inline]
+    pub fn contains_warn<V>(&self, w: &V) -> bool
+    where
+        V: PartialEq<W>,
+    {
+        match self {
+            Warn(_, warn) => w == warn,
+            Ok(_) | Err(_) => false,
+        }
+    }
+
+    /// Log the warning, if there is one, using [`log::warn!`]. The result is returned unchanged.
+    #[cfg(feature = "log")]
+    #[must_use]
+    pub fn log_warnings(self) -> Self
+    where
+        W: Debug,
+    {
+        if let Warn(_, warn) = &self {
+            log::warn!("{warn:?}");
+        }
+        self
+    }
+
+    /// Convert this result into its warning
+    ///
+    /// # Panics
+    ///
+    /// If the result is not `Warn`
+    #[track_caller]
+    pub fn unwrap_warn(self) -> W {
+        match self {
+            Warn(_, warn) => warn,
+            Ok(_) => panic!("Called `unwrap_warn` on Result::Ok"),
+            Err(_) => panic!("Called `unwrap_warn` on Result::Err"),
+        }
+    }
+
+    /// Convert this result into its warning, panicking with the provided message otherwise
+    ///
+    /// # Panics
+    ///
+    /// If the result is not `Warn`
+    #[track_caller]
+    pub fn expect_warn(self, msg: &str) -> W {
+        match self {
+            Warn(_, warn) => warn,
+            Ok(_) | Err(_) => panic!("{msg}"),
+        }
+    }
+
+    /// Convert this result into its error
+    ///
+    /// # Panics
+    ///
+    /// If the result is not `Err`
+    #[track_caller]
+    pub fn unwrap_err(self) -> E {
+        match self {
+            Err(err) => err,
+            Ok(_) => panic!("Called `unwrap_err` on Result::Ok"),
+            Warn(_, _) => panic!("Called `unwrap_err` on Result::Warn"),
+        }
+    }
+
+    /// Convert this result into its error, panicking with the provided message otherwise
+    ///
+    /// # Panics
+    ///
+    /// If the result is not `Err`
+    #[track_caller]
+    pub fn expect_err(self, msg: &str) -> E {
+        match self {
+            Err(err) => err,
+            Ok(_) | Warn(_, _) => panic!("{msg}"),
+        }
+    }
+
+    /// Convert this result into its value, calling `f` with the warning if there is one
+    ///
+    /// # Panics
+    ///
+    /// If the result is `Err`
+    #[track_caller]
+    pub fn unwrap_or_warn(self, f: impl FnOnce(W)) -> T {
+        match self {
+            Ok(val) => val,
+            Warn(val, warn) => {
+                f(warn);
+                val
+            }
+            Err(_) => panic!("Called `unwrap_or_warn` on Result::Err"),
+        }
+    }
+
+    /// Convert this result into its value and its warning, if there is one
+    ///
+    /// # Panics
+    ///
+    /// If the result is `Err`, with the error's debug output
+    #[track_caller]
+    pub fn unwrap_with_warn(self) -> (T, Option<W>)
+    where
+        E: Debug,
+    {
+        match self {
+            Ok(val) => (val, None),
+            Warn(val, warn) => (val, Some(warn)),
+            Err(err) => panic!("Called `unwrap_with_warn` on Result::Err: {err:?}"),
+        }
+    }
+
+    /// Convert this result into a core `Result`, calling `f` with the warning if there is one.
+    /// `Ok` and `Err` are passed through unchanged.
+    #[inline]
+    pub fn handle_warn(self, f: impl FnOnce(W)) -> CoreResult<T, E> {
+        match self {
+            Ok(val) => CoreResult::Ok(val),
+            Warn(val, warn) => {
+                f(warn);
+                CoreResult::Ok(val)
+            }
+            Err(err) => CoreResult::Err(err),
+        }
+    }
+
+    /// Convert this result into its value, discarding any warning, or return `default` if it is
+    /// `Err`
+    #[inline]
+    pub fn value_or(self, default: T) -> T {
+        match self {
+            Ok(val) | Warn(val, _) => val,
+            Err(_) => default,
+        }
+    }
+
+    /// Downgrade an `Ok` value which doesn't satisfy `pred` into `Warn` with `warn`, keeping the
+    /// value. `Warn` and `Err` are returned as-is, keeping the existing warning rather than
+    /// combining it with `warn`.
+    #[inline]
+    pub fn filter(self, pred: impl FnOnce(&T) -> bool, warn: W) -> Result<T, E, W> {
+        match self {
+            Ok(val) if !pred(&val) => Warn(val, warn),
+            other => other,
+        }
+    }
+
+    /// Downgrade `Warn` to `Ok` if the warning doesn't satisfy `pred`, discarding the warning.
+    /// `Ok` and `Err` are returned as-is.
+    #[inline]
+    pub fn retain_warn(self, pred: impl FnOnce(&W) -> bool) -> Result<T, E, W> {
+        match self {
+            Warn(val, warn) if !pred(&warn) => Ok(val),
+            other => other,
+        }
+    }
+
+    /// Collapse all three variants into a common type, applying `on_ok`, `on_warn`, or `on_err`
+    /// as appropriate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::warn_result::Result;
+    /// let res: Result<i32, &str, &str> = Result::Warn(1, "warn");
+    /// let report = res.fold_diagnostics(
+    ///     |val| format!("ok: {val}"),
+    ///     |val, warn| format!("{warn}: {val}"),
+    ///     |err| format!("error: {err}"),
+    /// );
+    /// assert_eq!(report, "warn: 1");
+    /// ```
+    #[inline]
+    pub fn fold_diagnostics<X>(
+        self,
+        on_ok: impl FnOnce(T) -> X,
+        on_warn: impl FnOnce(T, W) -> X,
+        on_err: impl FnOnce(E) -> X,
+    ) -> X {
+        match self {
+            Ok(val) => on_ok(val),
+            Warn(val, warn) => on_warn(val, warn),
+            Err(err) => on_err(err),
+        }
+    }
+
+    /// Process an iterator of results into the values of every `Ok` and `Warn`, along with the
+    /// errors and warnings, without stopping at the first `Err`. See [`partition`].
+    pub fn collect_diagnostics<I>(iter: I) -> (Vec<T>, Diagnostics<E, W>)
+    where
+        I: IntoIterator<Item = Result<T, E, W>>,
+    {
+        let (vals, warnings, errors) = partition(iter);
+        (vals, Diagnostics { errors, warnings })
+    }
+
+    /// Decompose this result into its value, error, and warning as independent optionals.
+    ///
+    /// Only three combinations are possible: `Ok` gives `(Some(val), None, None)`, `Warn` gives
+    /// `(Some(val), None, Some(warn))`, and `Err` gives `(None, Some(err), None)`. A value and an
+    /// error are never both present.
+    #[inline]
+    pub fn into_diagnostics(self) -> (Option<T>, Option<E>, Option<W>) {
+        match self {
+            Ok(val) => (Some(val), None, None),
+            Warn(val, warn) => (Some(val), None, Some(warn)),
+            Err(err) => (None, Some(err), None),
+        }
+    }
+
+    /// Split this result into its value and its diagnostic, if any.
+    ///
+    /// `Ok` gives `(Some(val), None)`, `Warn` gives `(Some(val), Some(Diag::Warn(warn)))`, and
+    /// `Err` gives `(None, Some(Diag::Err(err)))`. The value is only missing if the diagnostic is
+    /// an error.
+    #[inline]
+    pub fn split(self) -> (Option<T>, Option<Diag<E, W>>) {
+        match self {
+            Ok(val) => (Some(val), None),
+            Warn(val, warn) => (Some(val), Some(Diag::Warn(warn))),
+            Err(err) => (None, Some(Diag::Err(err))),
+        }
+    }
+
     #[inline]
     pub fn as_ref(&self) -> Result<&T, &E, &W> {
         match self {
@@ -57,11 +343,137 @@ impl<T, E, W> Result<T, E, W> {
         }
     }
 
+    /// Convert the value with [`Deref`], borrowing the warning and error
+    #[inline]
+    pub fn as_deref(&self) -> Result<&T::Target, &E, &W>
+    where
+        T: Deref,
+    {
+        match self {
+            Ok(val) => Ok(val),
+            Warn(val, warn) => Warn(val, warn),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Convert the value with [`DerefMut`], mutably borrowing the warning and error
+    #[inline]
+    pub fn as_deref_mut(&mut self) -> Result<&mut T::Target, &mut E, &mut W>
+    where
+        T: DerefMut,
+    {
+        match self {
+            Ok(val) => Ok(val),
+            Warn(val, warn) => Warn(val, warn),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Get a mutable reference to the warning, if there is one
+    #[inline]
+    pub fn warnings_mut(&mut self) -> Option<&mut W> {
+        match self {
+            Warn(_, warn) => Some(warn),
+            Ok(_) | Err(_) => None,
+        }
+    }
+
+    /// Add a warning to this result. `Ok` becomes `Warn` with a default warning extended by `w`,
+    /// `Warn` is extended by `w`, and `Err` is left unchanged.
+    pub fn push_warning<W1>(&mut self, w: W1)
+    where
+        W: Default + Extend<W1>,
+    {
+        match self {
+            Ok(val) => {
+                let mut warns = W::default();
+                warns.extend([w]);
+                // SAFETY: `val` is read out exactly once, and `self` is then overwritten without
+                //         being dropped. Nothing between the read and the write can panic.
+                unsafe {
+                    let val = ptr::read(val);
+                    ptr::write(self, Warn(val, warns));
+                }
+            }
+            Warn(_, warns) => warns.extend([w]),
+            Err(_) => (),
+        }
+    }
+
+    /// Replace this result with `new`, returning the old result
+    #[inline]
+    pub fn replace(&mut self, new: Result<T, E, W>) -> Result<T, E, W> {
+        mem::replace(self, new)
+    }
+
+    /// Take this result, leaving `Ok(T::default())` in its place
+    #[inline]
+    pub fn take(&mut self) -> Result<T, E, W>
+    where
+        T: Default,
+    {
+        mem::take(self)
+    }
+
+    /// Get a mutable reference to the value. If this result is `Err`, the error is dropped and
+    /// replaced with `Ok(val)` first.
+    pub fn get_or_insert(&mut self, val: T) -> &mut T {
+        if let Err(_) = self {
+            *self = Ok(val);
+        }
+        match self {
+            Ok(val) | Warn(val, _) => val,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Get a mutable reference to the warning. If this result is `Ok`, it becomes `Warn` with
+    /// `warn` and `val` is discarded. If it is `Err`, the error is dropped and replaced with
+    /// `Warn(val, warn)`.
+    pub fn warn_or_insert(&mut self, val: T, warn: W) -> &mut W {
+        match self {
+            Ok(old) => {
+                let old = mem::replace(old, val);
+                *self = Warn(old, warn);
+            }
+            Warn(_, _) => (),
+            Err(_) => *self = Warn(val, warn),
+        }
+        match self {
+            Warn(_, warn) => warn,
+            Ok(_) | Err(_) => unreachable!(),
+        }
+    }
+
     #[inline]
     pub fn map<U>(self, f: impl FnOnce(MaybeWarn<T, W>) -> MaybeWarn<U, W>) -> Result<U, E, W> {
         f(self?).into()
     }
 
+    /// Apply `f` to the value and warning, if there is one, or return `default` on an error.
+    #[inline]
+    pub fn map_or<U>(self, default: U, f: impl FnOnce(MaybeWarn<T, W>) -> U) -> U {
+        match self {
+            Ok(val) => f(MaybeWarn::Ok(val)),
+            Warn(val, warn) => f(MaybeWarn::Warn(val, warn)),
+            Err(_) => default,
+        }
+    }
+
+    /// Apply `f` to the value and warning, if there is one, or `default` to the error.
+    #[inline]
+    pub fn map_or_else<U>(
+        self,
+        default: impl FnOnce(E) -> U,
+        f: impl FnOnce(MaybeWarn<T, W>) -> U,
+    ) -> U {
+        match self {
+            Ok(val) => f(MaybeWarn::Ok(val)),
+            Warn(val, warn) => f(MaybeWarn::Warn(val, warn)),
+            Err(err) => default(err),
+        }
+    }
+
     #[inline]
     pub fn map_val<U>(self, f: impl FnOnce(T) -> U) -> Result<U, E, W> {
         match self {
@@ -71,6 +483,20 @@ impl<T, E, W> Result<T, E, W> {
         }
     }
 
+    /// Apply `f` to the value and a reference to the warning, if there is one. The warning is kept
+    /// in the output unchanged.
+    #[inline]
+    pub fn map_with_warn<U>(self, f: impl FnOnce(T, Option<&W>) -> U) -> Result<U, E, W> {
+        match self {
+            Ok(val) => Ok(f(val, None)),
+            Warn(val, warn) => {
+                let val = f(val, Some(&warn));
+                Warn(val, warn)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     #[inline]
     pub fn map_warn<U>(self, f: impl FnOnce(W) -> U) -> Result<T, E, U> {
         match self {
@@ -89,6 +515,56 @@ impl<T, E, W> Result<T, E, W> {
         }
     }
 
+    /// Convert the error into a wider type with [`From`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::warn_result::Result;
+    /// let res: Result<i32, u8, u16> = Result::Warn(1, 2);
+    /// let res: Result<i32, u64, u64> = res.err_into().warn_into();
+    /// assert_eq!(res, Result::Warn(1, 2u64));
+    /// ```
+    #[inline]
+    pub fn err_into<F: From<E>>(self) -> Result<T, F, W> {
+        self.map_err(F::from)
+    }
+
+    /// Convert the warning into a wider type with [`From`]
+    #[inline]
+    pub fn warn_into<V: From<W>>(self) -> Result<T, E, V> {
+        self.map_warn(V::from)
+    }
+
+    /// Apply `f_val` to the value and `f_warn` to the warning, leaving an error untouched
+    #[inline]
+    pub fn map_both<U, F>(
+        self,
+        f_val: impl FnOnce(T) -> U,
+        f_warn: impl FnOnce(W) -> F,
+    ) -> Result<U, E, F> {
+        match self {
+            Ok(val) => Ok(f_val(val)),
+            Warn(val, warn) => Warn(f_val(val), f_warn(warn)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Apply `f_val` to the value, `f_warn` to the warning, and `f_err` to the error
+    #[inline]
+    pub fn map_all<U, F, G>(
+        self,
+        f_val: impl FnOnce(T) -> U,
+        f_warn: impl FnOnce(W) -> F,
+        f_err: impl FnOnce(E) -> G,
+    ) -> Result<U, G, F> {
+        match self {
+            Ok(val) => Ok(f_val(val)),
+            Warn(val, warn) => Warn(f_val(val), f_warn(warn)),
+            Err(err) => Err(f_err(err)),
+        }
+    }
+
     #[inline]
     pub fn and_then<U>(
         self,
@@ -97,6 +573,67 @@ impl<T, E, W> Result<T, E, W> {
         f(self?)
     }
 
+    /// Like [`and_then`](Self::and_then), but for a function returning a [`core::result::Result`].
+    ///
+    /// If `f` returns `Ok`, the new value is combined with the warning of this result, if there
+    /// was one - `Warn(val, warn)` becomes `Warn(f(..), warn)`. If `f` returns `Err`, the warning
+    /// is discarded and the error is returned. As `f` is also given the warning, it is cloned so
+    /// it can be reattached, hence the `W: Clone` bound.
+    #[inline]
+    pub fn and_then_core<U>(
+        self,
+        f: impl FnOnce(MaybeWarn<T, W>) -> CoreResult<U, E>,
+    ) -> Result<U, E, W>
+    where
+        W: Clone,
+    {
+        let val = self?;
+        let warn = match &val {
+            MaybeWarn::Ok(_) => None,
+            MaybeWarn::Warn(_, warn) => Some(warn.clone()),
+        };
+        match (f(val), warn) {
+            (CoreResult::Ok(val), None) => Ok(val),
+            (CoreResult::Ok(val), Some(warn)) => Warn(val, warn),
+            (CoreResult::Err(err), _) => Err(err),
+        }
+    }
+
+    /// Apply `f` to the value, keeping any existing warning. If both this result and the output
+    /// of `f` carry a warning, the new warning is appended to the existing one with [`Extend`].
+    /// If `f` returns `Err`, the existing warning is discarded. See
+    /// [`and_then_warn_with`](Self::and_then_warn_with) to combine warnings some other way.
+    #[inline]
+    pub fn and_then_warn<U>(self, f: impl FnOnce(T) -> Result<U, E, W>) -> Result<U, E, W>
+    where
+        W: IntoIterator + Extend<W::Item>,
+    {
+        self.and_then_warn_with(f, |mut a, b| {
+            a.extend(b);
+            a
+        })
+    }
+
+    /// Apply `f` to the value, keeping any existing warning. If both this result and the output
+    /// of `f` carry a warning, they are combined with `combine`, with the existing warning first.
+    /// If `f` returns `Err`, the existing warning is discarded.
+    #[inline]
+    pub fn and_then_warn_with<U>(
+        self,
+        f: impl FnOnce(T) -> Result<U, E, W>,
+        combine: impl FnOnce(W, W) -> W,
+    ) -> Result<U, E, W> {
+        match self {
+            Ok(val) => f(val),
+            Warn(val, warn) => match f(val) {
+                Ok(out) => Warn(out, warn),
+                Warn(out, new) => Warn(out, combine(warn, new)),
+                Err(err) => Err(err),
+            },
+            Err(err) => Err(err),
+        }
+    }
+
     #[inline]
     pub fn or_else<U>(self, f: impl FnOnce(E) -> Result<T, U, W>) -> Result<T, U, W> {
         match self {
@@ -143,6 +680,116 @@ impl<T, E, W> Result<&mut T, E, W> {
     }
 }
 
+impl<T, E, W> Result<&T, &E, &W> {
+    /// Copy the value, error, and warning into an owned result
+    #[inline]
+    pub fn copied_all(self) -> Result<T, E, W>
+    where
+        T: Copy,
+        E: Copy,
+        W: Copy,
+    {
+        self.map_all(|val| *val, |warn| *warn, |err| *err)
+    }
+
+    /// Clone the value, error, and warning into an owned result
+    #[inline]
+    pub fn cloned_all(self) -> Result<T, E, W>
+    where
+        T: Clone,
+        E: Clone,
+        W: Clone,
+    {
+        self.map_all(T::clone, W::clone, E::clone)
+    }
+}
+
+impl<T, E, W> Result<T, E, &W> {
+    /// Copy the referenced warning into an owned one
+    #[inline]
+    pub fn copied_warn(self) -> Result<T, E, W>
+    where
+        W: Copy,
+    {
+        self.map_warn(|warn| *warn)
+    }
+
+    /// Clone the referenced warning into an owned one
+    #[inline]
+    pub fn cloned_warn(self) -> Result<T, E, W>
+    where
+        W: Clone,
+    {
+        self.map_warn(W::clone)
+    }
+}
+
+impl<T, E, W> Result<T, E, Vec<W>> {
+    /// Collect an iterator of results, gathering all warnings into a `Vec` in iteration order.
+    /// See the [`FromIterator`] implementation for details.
+    pub fn from_iter_warns<T1>(iter: impl IntoIterator<Item = Result<T1, E, W>>) -> Self
+    where
+        T: FromIterator<T1>,
+    {
+        iter.into_iter().collect()
+    }
+
+    /// Keep only the warnings satisfying `pred`. If no warnings remain, `Warn` is downgraded to
+    /// `Ok`.
+    pub fn retain_warns(self, mut pred: impl FnMut(&W) -> bool) -> Self {
+        match self {
+            Warn(val, mut warns) => {
+                warns.retain(|warn| pred(warn));
+                if warns.is_empty() {
+                    Ok(val)
+                } else {
+                    Warn(val, warns)
+                }
+            }
+            other => other,
+        }
+    }
+}
+
+impl<T, E, W> Result<T, Vec<E>, W> {
+    /// Collect an iterator of results, like the [`FromIterator`] implementation, but without
+    /// stopping at the first `Err`. If any item was `Err`, every error is returned in iteration
+    /// order. Otherwise, warnings are accumulated the same as in [`FromIterator`].
+    pub fn try_collect_all<T1, W1>(iter: impl IntoIterator<Item = Result<T1, E, W1>>) -> Self
+    where
+        T: FromIterator<T1>,
+        W: Default + Extend<W1>,
+    {
+        let mut warned = false;
+        let mut warns = W::default();
+        let mut errs = Vec::new();
+
+        let out = iter
+            .into_iter()
+            .filter_map(|item| match item {
+                Ok(val) => Some(val),
+                Warn(val, warn) => {
+                    warned = true;
+                    warns.extend([warn]);
+                    Some(val)
+                }
+                Err(err) => {
+                    errs.push(err);
+                    None
+                }
+            })
+            .collect();
+
+        if !errs.is_empty() {
+            Err(errs)
+        } else if warned {
+            Warn(out, warns)
+        } else {
+            Ok(out)
+        }
+    }
+}
+
 impl<T, E, W> Result<Option<T>, E, W> {
     pub fn transpose_lossy(self) -> Option<Result<T, E, W>> {
         match self {
@@ -154,9 +801,50 @@ impl<T, E, W> Result<Option<T>, E, W> {
             Err(err) => Some(Err(err)),
         }
     }
+
+    /// Collapse a result containing an `Option` into a result of the inner value, returning
+    /// `Err(err)` if the value is `None`. Any warning on a `None` value is discarded.
+    #[inline]
+    pub fn flatten_option_or(self, err: E) -> Result<T, E, W> {
+        match self {
+            Ok(Some(val)) => Ok(val),
+            Warn(Some(val), warn) => Warn(val, warn),
+            Ok(None) | Warn(None, _) => Err(err),
+            Err(err) => Err(err),
+        }
+    }
 }
 
 impl<T, E, W> Result<Result<T, E, W>, E, W> {
+    /// Flatten a nested result, keeping both warnings if both levels warn. The inner warning is
+    /// appended to the outer warning with [`Extend`]. See [`flatten_with`](Self::flatten_with) to
+    /// combine warnings some other way.
+    ///
+    /// An `Err` at either level produces `Err`. An outer warning on an inner `Err` is discarded,
+    /// as `Err` has no place to hold it - in every other case, no warning is lost.
+    #[inline]
+    pub fn flatten(self) -> Result<T, E, W>
+    where
+        W: IntoIterator + Extend<W::Item>,
+    {
+        self.flatten_with(|mut outer, inner| {
+            outer.extend(inner);
+            outer
+        })
+    }
+
+    /// Flatten a nested result, combining the outer and inner warnings with `combine` if both
+    /// levels warn. Otherwise, behaves the same as [`flatten`](Self::flatten).
+    #[inline]
+    pub fn flatten_with(self, combine: impl FnOnce(W, W) -> W) -> Result<T, E, W> {
+        match self {
+            Ok(Ok(val)) => Ok(val),
+            Ok(Warn(val, warn)) | Warn(Ok(val), warn) => Warn(val, warn),
+            Warn(Warn(val, inner), outer) => Warn(val, combine(outer, inner)),
+            Ok(Err(err)) | Warn(Err(err), _) | Err(err) => Err(err),
+        }
+    }
+
     #[inline]
     pub fn flatten_inner(self) -> Result<T, E, W> {
         match self {
@@ -185,6 +873,44 @@ impl<T, E, W> Result<Result<T, E, W>, E, W> {
     }
 }
 
+impl<A, B, E, W> Result<(A, B), E, W> {
+    /// Split a result holding a pair into a pair of results. The warning or error, if any, is
+    /// cloned into both sides, so each consumer sees the full diagnostics.
+    #[inline]
+    pub fn unzip(self) -> (Result<A, E, W>, Result<B, E, W>)
+    where
+        E: Clone,
+        W: Clone,
+    {
+        match self {
+            Ok((a, b)) => (Ok(a), Ok(b)),
+            Warn((a, b), warn) => (Warn(a, warn.clone()), Warn(b, warn)),
+            Err(err) => (Err(err.clone()), Err(err)),
+        }
+    }
+}
+
+impl<T, E, W> Result<CoreResult<T, E>, E, W> {
+    /// Flatten a result wrapping a core `Result` with the same error type. An inner `Err` becomes
+    /// `Err`, discarding any outer warning, while an inner `Ok` keeps the outer warning, if any.
+    #[inline]
+    pub fn flatten_core(self) -> Result<T, E, W> {
+        match self {
+            Ok(CoreResult::Ok(val)) => Ok(val),
+            Warn(CoreResult::Ok(val), warn) => Warn(val, warn),
+            Ok(CoreResult::Err(err)) | Warn(CoreResult::Err(err), _) | Err(err) => Err(err),
+        }
+    }
+}
+
+impl<T: Default, E, W> Default for Result<T, E, W> {
+    /// Returns `Ok` with the default value of `T`
+    #[inline]
+    fn default() -> Self {
+        Ok(T::default())
+    }
+}
+
 impl<T, E, W> From<MaybeWarn<T, W>> for Result<T, E, W> {
     #[inline]
     fn from(m: MaybeWarn<T, W>) -> Self {
@@ -244,6 +970,17 @@ impl<T, E, W> FromResidual<CoreResult<Infallible, E>> for Result<T, E, W> {
     }
 }
 
+/// Using `?` on an [`Early`] returns its `Done` value as an `Err`, converted with [`Into`]
+impl<T, E, W, D: Into<E>> FromResidual<Early<D, Infallible>> for Result<T, E, W> {
+    #[inline]
+    fn from_residual(residual: Early<D, Infallible>) -> Self {
+        match residual {
+            Early::Done(done) => Err(done.into()),
+            Early::Todo(val) => match val {},
+        }
+    }
+}
+
 #[cfg(feature = "yeet")]
 impl<T, E, W> FromResidual<Yeet<E>> for Result<T, E, W> {
     fn from_residual(residual: Yeet<E>) -> Self {
@@ -251,22 +988,43 @@ impl<T, E, W> FromResidual<Yeet<E>> for Result<T, E, W> {
     }
 }
 
+/// Report a warning or error from `main`. With the `log` feature enabled, this goes through the
+/// `log` crate if a logger is enabled for the level, otherwise it falls back to stderr.
+fn report_diagnostic(is_err: bool, val: impl Debug) {
+    #[cfg(feature = "log")]
+    {
+        let level = if is_err { log::Level::Error } else { log::Level::Warn };
+        if log::log_enabled!(level) {
+            log::log!(level, "{val:?}");
+            return;
+        }
+    }
+    let prefix = if is_err { "Error" } else { "Warning" };
+    drop(writeln!(io::stderr(), "{prefix}: {val:?}"));
+}
+
+/// Reports any warning or error to stderr. If the `log` feature is enabled, they are reported
+/// through the `log` crate instead, as long as a logger is installed and enabled for the level -
+/// so an error is never silently lost when no logger is set up.
 impl<T: Termination, E: Debug, W: Debug> Termination for Result<T, E, W> {
     fn report(self) -> ExitCode {
         match self {
             Ok(val) => val.report(),
             Warn(val, warn) => {
-                drop(writeln!(io::stderr(), "Warning: {warn:?}"));
+                report_diagnostic(false, warn);
                 val.report()
             }
             Err(err) => {
-                drop(writeln!(io::stderr(), "Error: {err:?}"));
+                report_diagnostic(true, err);
                 ExitCode::FAILURE
             }
         }
     }
 }
 
+/// Collect an iterator of results into a single result. Iteration stops at the first `Err`, which
+/// is returned. Otherwise, if any item was `Warn`, the result is `Warn` with every warning passed
+/// to [`Extend::extend`] one at a time, in iteration order.
 impl<T, T1, E, W, W1> FromIterator<Result<T1, E, W1>> for Result<T, E, W>
 where
     T: FromIterator<T1>,
@@ -327,3 +1085,604 @@ where
         }
     }
 }
+
+/// Drain an iterator of results into its values, warnings, and errors, without stopping at the
+/// first `Err`. Values from both `Ok` and `Warn` are collected, and each output preserves the
+/// iteration order of the items it was taken from.
+pub fn partition<I, T, E, W>(iter: I) -> (Vec<T>, Vec<W>, Vec<E>)
+where
+    I: IntoIterator<Item = Result<T, E, W>>,
+{
+    let (mut vals, mut warns, mut errs) = (Vec::new(), Vec::new(), Vec::new());
+    for item in iter {
+        match item {
+            Ok(val) => vals.push(val),
+            Warn(val, warn) => {
+                vals.push(val);
+                warns.push(warn);
+            }
+            Err(err) => errs.push(err),
+        }
+    }
+    (vals, warns, errs)
+}
+
+/// Return early with `Err(err)` from a function returning a [`Result`] if `cond` is false.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::warn_result::Result;
+/// # use trying::ensure_warn;
+/// fn halve(a: u32) -> Result<u32, &'static str> {
+///     ensure_warn!(a % 2 == 0, "odd number");
+///     Result::Ok(a / 2)
+/// }
+/// assert_eq!(halve(3), Result::Err("odd number"));
+/// ```
+#[macro_export]
+macro_rules! ensure_warn {
+    ($cond:expr, $err:expr $(,)?) => {
+        if !$cond {
+            return $crate::warn_result::Result::Err($err);
+        }
+    };
+}
+
+/// Produce `Warn(val, warn)` if `cond` is true, otherwise `Ok(val)`. The warning is only
+/// evaluated if `cond` is true.
+///
+/// # Examples
+///
+/// ```
+/// # use trying::warn_result::Result;
+/// # use trying::warn_if;
+/// let a = 200u8;
+/// let res: Result<u8, (), &str> = warn_if!(a > 100, a, "large value");
+/// assert_eq!(res, Result::Warn(200, "large value"));
+/// ```
+#[macro_export]
+macro_rules! warn_if {
+    ($cond:expr, $val:expr, $warn:expr $(,)?) => {
+        if $cond {
+            $crate::warn_result::Result::Warn($val, $warn)
+        } else {
+            $crate::warn_result::Result::Ok($val)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_iter_warn_order() {
+        let items: [Result<i32, (), &str>; 4] = [Warn(1, "a"), Ok(2), Warn(3, "b"), Warn(4, "c")];
+        let out: Result<Vec<_>, (), Vec<_>> = items.into_iter().collect();
+        assert_eq!(out, Warn(vec![1, 2, 3, 4], vec!["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_from_iter_warns() {
+        let items: [Result<i32, (), &str>; 3] = [Warn(1, "a"), Warn(2, "b"), Err(())];
+        assert_eq!(Result::<Vec<_>, _, _>::from_iter_warns(items), Err(()));
+
+        let items: [Result<i32, (), &str>; 2] = [Warn(1, "b"), Warn(2, "a")];
+        let out = Result::<Vec<_>, _, _>::from_iter_warns(items);
+        assert_eq!(out, Warn(vec![1, 2], vec!["b", "a"]));
+    }
+
+    #[test]
+    fn test_try_collect_all() {
+        let items: [Result<i32, &str, &str>; 4] = [Err("a"), Warn(1, "w"), Ok(2), Err("b")];
+        let out: Result<Vec<_>, _, Vec<_>> = Result::try_collect_all(items);
+        assert_eq!(out, Err(vec!["a", "b"]));
+
+        let items: [Result<i32, &str, &str>; 2] = [Warn(1, "w"), Ok(2)];
+        let out: Result<Vec<_>, _, Vec<_>> = Result::try_collect_all(items);
+        assert_eq!(out, Warn(vec![1, 2], vec!["w"]));
+    }
+
+    #[test]
+    fn test_push_warning() {
+        let mut res: Result<String, (), Vec<&str>> = Ok(String::from("val"));
+        res.push_warning("a");
+        res.push_warning("b");
+        assert_eq!(res, Warn(String::from("val"), vec!["a", "b"]));
+
+        res.warnings_mut().unwrap().push("c");
+        assert_eq!(res, Warn(String::from("val"), vec!["a", "b", "c"]));
+
+        let mut res: Result<String, (), Vec<&str>> = Err(());
+        res.push_warning("a");
+        assert_eq!(res, Err(()));
+    }
+
+    #[test]
+    fn test_unwrap_warn() {
+        let res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.unwrap_warn(), "warn");
+        assert_eq!(res.expect_warn("no warning"), "warn");
+    }
+
+    #[test]
+    #[should_panic = "Called `unwrap_warn` on Result::Ok"]
+    fn test_unwrap_warn_failure() {
+        let res: Result<i32, (), &str> = Ok(1);
+        res.unwrap_warn();
+    }
+
+    #[test]
+    fn test_unwrap_err() {
+        let res: Result<i32, &str, ()> = Err("err");
+        assert_eq!(res.unwrap_err(), "err");
+        assert_eq!(res.expect_err("no error"), "err");
+    }
+
+    #[test]
+    #[should_panic = "no error"]
+    fn test_expect_err_failure() {
+        let res: Result<i32, &str, ()> = Warn(1, ());
+        res.expect_err("no error");
+    }
+
+    #[test]
+    fn test_flatten_option_or() {
+        let res: Result<Option<i32>, &str, &str> = Warn(Some(1), "warn");
+        assert_eq!(res.flatten_option_or("none"), Warn(1, "warn"));
+
+        let res: Result<Option<i32>, &str, &str> = Ok(None);
+        assert_eq!(res.flatten_option_or("none"), Err("none"));
+
+        let res: Result<Option<i32>, &str, &str> = Err("err");
+        assert_eq!(res.flatten_option_or("none"), Err("err"));
+    }
+
+    #[test]
+    fn test_unwrap_or_warn() {
+        let mut warns = Vec::new();
+        let res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.unwrap_or_warn(|w| warns.push(w)), 1);
+        assert_eq!(warns, ["warn"]);
+    }
+
+    #[test]
+    fn test_handle_warn() {
+        let mut warns = Vec::new();
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.handle_warn(|w| warns.push(w)), CoreResult::Ok(1));
+        let res: Result<i32, &str, &str> = Err("err");
+        assert_eq!(res.handle_warn(|w| warns.push(w)), CoreResult::Err("err"));
+        assert_eq!(warns, ["warn"]);
+    }
+
+    #[test]
+    fn test_value_or() {
+        let res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.value_or(2), 1);
+        let res: Result<i32, (), &str> = Err(());
+        assert_eq!(res.value_or(2), 2);
+    }
+
+    #[test]
+    fn test_map_both() {
+        let res: Result<i32, i32, i32> = Warn(1, 2);
+        assert_eq!(res.map_both(|v| v + 1, |w| w * 2), Warn(2, 4));
+        let res: Result<i32, i32, i32> = Err(3);
+        assert_eq!(res.map_both(|v| v + 1, |w| w * 2), Err(3));
+        assert_eq!(res.map_all(|v| v + 1, |w| w * 2, |e| e - 1), Err(2));
+    }
+
+    #[test]
+    fn test_contains() {
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert!(res.contains(&1));
+        assert!(!res.contains(&2));
+        assert!(res.contains_warn(&"warn"));
+        assert!(!res.contains_err(&"warn"));
+
+        let res: Result<i32, &str, &str> = Err("err");
+        assert!(!res.contains(&1));
+        assert!(res.contains_err(&"err"));
+        assert!(!res.contains_warn(&"err"));
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(Result::<i32, (), ()>::default(), Ok(0));
+    }
+
+    #[test]
+    fn test_split() {
+        let res: Result<i32, &str, &str> = Ok(1);
+        assert_eq!(res.into_diagnostics(), (Some(1), None, None));
+        assert_eq!(res.split(), (Some(1), None));
+
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.into_diagnostics(), (Some(1), None, Some("warn")));
+        assert_eq!(res.split(), (Some(1), Some(Diag::Warn("warn"))));
+
+        let res: Result<i32, &str, &str> = Err("err");
+        assert_eq!(res.into_diagnostics(), (None, Some("err"), None));
+        assert_eq!(res.split(), (None, Some(Diag::Err("err"))));
+    }
+
+    #[test]
+    fn test_maybe_warn_eq() {
+        let mut out = None;
+        let _ = (|| -> Result<(), (), &str> {
+            out = Some(Warn(1, "warn")?);
+            Ok(())
+        })();
+        assert_eq!(out, Some(MaybeWarn::Warn(1, "warn")));
+    }
+
+    #[test]
+    fn test_cloned_warn() {
+        let warn = String::from("warn");
+        let res: Result<i32, (), &String> = Warn(1, &warn);
+        assert_eq!(res.cloned_warn(), Warn(1, String::from("warn")));
+        let res: Result<i32, (), &i32> = Warn(1, &2);
+        assert_eq!(res.copied_warn(), Warn(1, 2));
+    }
+
+    #[test]
+    fn test_get_or_insert() {
+        let mut res: Result<i32, &str, Vec<&str>> = Err("err");
+        *res.get_or_insert(1) += 1;
+        assert_eq!(res, Ok(2));
+
+        res.warn_or_insert(0, Vec::new()).push("a");
+        res.warn_or_insert(0, Vec::new()).push("b");
+        assert_eq!(res, Warn(2, vec!["a", "b"]));
+
+        let mut res: Result<i32, &str, Vec<&str>> = Err("err");
+        res.warn_or_insert(0, vec!["a"]);
+        assert_eq!(res, Warn(0, vec!["a"]));
+    }
+
+    #[test]
+    fn test_flatten_core() {
+        let res: Result<CoreResult<i32, &str>, &str, &str> = Warn(CoreResult::Ok(1), "warn");
+        assert_eq!(res.flatten_core(), Warn(1, "warn"));
+        let res: Result<CoreResult<i32, &str>, &str, &str> = Warn(CoreResult::Err("inner"), "warn");
+        assert_eq!(res.flatten_core(), Err("inner"));
+        let res: Result<CoreResult<i32, &str>, &str, &str> = Err("outer");
+        assert_eq!(res.flatten_core(), Err("outer"));
+    }
+
+    #[test]
+    fn test_as_deref() {
+        let res: Result<String, (), &str> = Warn(String::from("val"), "warn");
+        assert_eq!(res.as_deref(), Warn("val", &"warn"));
+
+        let mut res: Result<Vec<i32>, (), ()> = Ok(vec![1, 2]);
+        res.as_deref_mut().map_val(|val| val[0] = 3);
+        assert_eq!(res, Ok(vec![3, 2]));
+    }
+
+    #[test]
+    fn test_maybe_warn_and_then() {
+        let val: MaybeWarn<i32, Vec<&str>> = MaybeWarn::Warn(1, vec!["a"]);
+        let out = val.and_then(|v| MaybeWarn::Warn(v + 1, vec!["b"]));
+        assert_eq!(out, MaybeWarn::Warn(2, vec!["a", "b"]));
+
+        let val: MaybeWarn<i32, Vec<&str>> = MaybeWarn::Ok(1);
+        assert_eq!(val.and_then(|v| MaybeWarn::Ok(v + 1)), MaybeWarn::Ok(2));
+
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Warn(1, "a");
+        let out = val.and_then_with(|v| MaybeWarn::Warn(v + 1, "b"), |_, b| b);
+        assert_eq!(out, MaybeWarn::Warn(2, "b"));
+    }
+
+    #[test]
+    fn test_filter() {
+        let res: Result<i32, (), &str> = Ok(-1);
+        assert_eq!(res.filter(|v| *v >= 0, "negative"), Warn(-1, "negative"));
+        let res: Result<i32, (), &str> = Ok(1);
+        assert_eq!(res.filter(|v| *v >= 0, "negative"), Ok(1));
+        let res: Result<i32, (), &str> = Warn(-1, "warn");
+        assert_eq!(res.filter(|v| *v >= 0, "negative"), Warn(-1, "warn"));
+    }
+
+    #[test]
+    fn test_ensure_warn() {
+        fn check(a: i32) -> Result<i32, &'static str, &'static str> {
+            crate::ensure_warn!(a >= 0, "negative");
+            crate::warn_if!(a == 0, a, "zero")
+        }
+
+        assert_eq!(check(-1), Err("negative"));
+        assert_eq!(check(0), Warn(0, "zero"));
+        assert_eq!(check(1), Ok(1));
+    }
+
+    #[test]
+    fn test_from_early() {
+        fn check(e: Early<&'static str, i32>) -> Result<i32, String, ()> {
+            let val = e?;
+            Ok(val + 1)
+        }
+
+        assert_eq!(check(Early::Todo(1)), Ok(2));
+        assert_eq!(check(Early::Done("done")), Err(String::from("done")));
+    }
+
+    #[test]
+    fn test_maybe_warn_warn_or() {
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Warn(1, "warn");
+        assert_eq!(val.warn_or("none"), CoreResult::Ok("warn"));
+        assert_eq!(val.expect_warn("no warning"), "warn");
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Ok(1);
+        assert_eq!(val.warn_or("none"), CoreResult::Err("none"));
+    }
+
+    #[test]
+    #[should_panic = "no warning"]
+    fn test_maybe_warn_expect_warn_failure() {
+        MaybeWarn::<i32, &str>::Ok(1).expect_warn("no warning");
+    }
+
+    #[test]
+    fn test_retain_warn() {
+        let res: Result<i32, (), u8> = Warn(1, 1);
+        assert_eq!(res.retain_warn(|w| *w > 2), Ok(1));
+        let res: Result<i32, (), u8> = Warn(1, 3);
+        assert_eq!(res.retain_warn(|w| *w > 2), Warn(1, 3));
+
+        let res: Result<i32, (), Vec<u8>> = Warn(1, vec![1, 3, 2, 4]);
+        assert_eq!(res.retain_warns(|w| *w > 2), Warn(1, vec![3, 4]));
+        let res: Result<i32, (), Vec<u8>> = Warn(1, vec![1, 2]);
+        assert_eq!(res.retain_warns(|w| *w > 2), Ok(1));
+    }
+
+    #[test]
+    fn test_unwrap_with_warn() {
+        let res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.unwrap_with_warn(), (1, Some("warn")));
+        let res: Result<i32, (), &str> = Ok(1);
+        assert_eq!(res.unwrap_with_warn(), (1, None));
+    }
+
+    #[test]
+    #[should_panic = "Called `unwrap_with_warn` on Result::Err: \"err\""]
+    fn test_unwrap_with_warn_failure() {
+        let res: Result<i32, &str, &str> = Err("err");
+        res.unwrap_with_warn();
+    }
+
+    #[test]
+    fn test_partition() {
+        let items: [Result<i32, &str, &str>; 5] =
+            [Ok(1), Err("a"), Warn(2, "w1"), Err("b"), Warn(3, "w2")];
+        let (vals, warns, errs) = partition(items);
+        assert_eq!(vals, [1, 2, 3]);
+        assert_eq!(warns, ["w1", "w2"]);
+        assert_eq!(errs, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_maybe_warn_transpose() {
+        assert_eq!(MaybeWarn::<_, ()>::Ok(Some(1)).transpose(), Some(MaybeWarn::Ok(1)));
+        assert_eq!(MaybeWarn::<Option<i32>, ()>::Ok(None).transpose(), None);
+        assert_eq!(MaybeWarn::Warn(Some(1), "w").transpose(), Some(MaybeWarn::Warn(1, "w")));
+        assert_eq!(MaybeWarn::<Option<i32>, _>::Warn(None, "w").transpose(), None);
+    }
+
+    #[test]
+    fn test_cloned_all() {
+        let res: Result<String, String, String> = Warn(String::from("a"), String::from("b"));
+        assert_eq!(res.as_ref().cloned_all(), res);
+        let res: Result<String, String, String> = Err(String::from("c"));
+        assert_eq!(res.as_ref().cloned_all(), res);
+
+        let res: Result<i32, u8, u16> = Warn(1, 2);
+        assert_eq!(res.as_ref().copied_all(), res);
+    }
+
+    #[test]
+    fn test_flatten() {
+        type Flat = Result<i32, &'static str, Vec<&'static str>>;
+        type Nested = Result<Flat, &'static str, Vec<&'static str>>;
+
+        let cases: [(Nested, Flat); 7] = [
+            (Ok(Ok(1)), Ok(1)),
+            (Ok(Warn(1, vec!["inner"])), Warn(1, vec!["inner"])),
+            (Ok(Err("inner")), Err("inner")),
+            (Warn(Ok(1), vec!["outer"]), Warn(1, vec!["outer"])),
+            (Warn(Warn(1, vec!["inner"]), vec!["outer"]), Warn(1, vec!["outer", "inner"])),
+            (Warn(Err("inner"), vec!["outer"]), Err("inner")),
+            (Err("outer"), Err("outer")),
+        ];
+        for (nested, flat) in cases {
+            assert_eq!(nested.flatten(), flat);
+        }
+
+        let res: Result<Result<i32, (), &str>, (), &str> = Warn(Warn(1, "inner"), "outer");
+        assert_eq!(res.flatten_with(|_, inner| inner), Warn(1, "inner"));
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut items: Vec<Result<i32, i32, i32>> =
+            vec![Err(0), Warn(2, 0), Ok(3), Warn(1, 5), Ok(1), Warn(1, 2)];
+        items.sort();
+        assert_eq!(items, [Ok(1), Ok(3), Warn(1, 2), Warn(1, 5), Warn(2, 0), Err(0)]);
+    }
+
+    #[test]
+    fn test_maybe_warn_inspect() {
+        let (mut vals, mut warns) = (Vec::new(), Vec::new());
+        let val = MaybeWarn::Warn(1, "warn")
+            .inspect(|v| vals.push(*v))
+            .inspect_warn(|w| warns.push(*w));
+        assert_eq!(val, MaybeWarn::Warn(1, "warn"));
+
+        MaybeWarn::<_, &str>::Ok(2)
+            .inspect(|v| vals.push(*v))
+            .inspect_warn(|w| warns.push(*w));
+        assert_eq!(vals, [1, 2]);
+        assert_eq!(warns, ["warn"]);
+    }
+
+    #[test]
+    fn test_unzip() {
+        let res: Result<(i32, &str), (), &str> = Warn((1, "a"), "warn");
+        assert_eq!(res.unzip(), (Warn(1, "warn"), Warn("a", "warn")));
+        let res: Result<(i32, &str), &str, ()> = Err("err");
+        assert_eq!(res.unzip(), (Err("err"), Err("err")));
+    }
+
+    #[test]
+    fn test_and_then_warn() {
+        let res: Result<i32, (), Vec<&str>> = Warn(1, vec!["a"]);
+        assert_eq!(res.and_then_warn(|v| Warn(v + 1, vec!["b"])), Warn(2, vec!["a", "b"]));
+
+        let res: Result<i32, (), Vec<&str>> = Warn(1, vec!["a"]);
+        assert_eq!(res.and_then_warn(|v| Ok(v + 1)), Warn(2, vec!["a"]));
+
+        let res: Result<i32, (), Vec<&str>> = Ok(1);
+        assert_eq!(res.and_then_warn(|v| Warn(v + 1, vec!["b"])), Warn(2, vec!["b"]));
+
+        let res: Result<i32, (), Vec<&str>> = Warn(1, vec!["a"]);
+        assert_eq!(res.and_then_warn(|_| Err::<i32, _, _>(())), Err(()));
+
+        let res: Result<i32, (), &str> = Warn(1, "a");
+        assert_eq!(res.and_then_warn_with(|v| Warn(v + 1, "b"), |_, b| b), Warn(2, "b"));
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.replace(Ok(2)), Warn(1, "warn"));
+        assert_eq!(res.take(), Ok(2));
+        assert_eq!(res, Ok(0));
+    }
+
+    #[test]
+    fn test_map_with_warn() {
+        let res: Result<i32, (), &str> = Warn(1, "warn");
+        assert_eq!(res.map_with_warn(|v, w| (v, w.copied())), Warn((1, Some("warn")), "warn"));
+        let res: Result<i32, (), &str> = Ok(1);
+        assert_eq!(res.map_with_warn(|v, w| (v, w.copied())), Ok((1, None)));
+    }
+
+    #[test]
+    fn test_collect_diagnostics() {
+        let items: [Result<i32, &str, &str>; 4] = [Ok(1), Warn(2, "w"), Err("e"), Ok(3)];
+        let (vals, diags) = Result::collect_diagnostics(items);
+        assert_eq!(vals, [1, 2, 3]);
+        assert_eq!(diags.errors, ["e"]);
+        assert_eq!(diags.warnings, ["w"]);
+        assert!(diags.has_errors());
+        assert!(!diags.is_empty());
+
+        let items: [Result<i32, &str, &str>; 2] = [Ok(1), Warn(2, "w")];
+        let (_, diags) = Result::collect_diagnostics(items);
+        assert!(!diags.has_errors());
+        assert!(!diags.is_empty());
+
+        let (_, diags) = Result::<i32, &str, &str>::collect_diagnostics([Ok(1)]);
+        assert!(diags.is_empty());
+    }
+
+    #[test]
+    fn test_maybe_warn_from_result() {
+        let val: MaybeWarn<i32, &str> = MaybeWarn::from_result(CoreResult::Ok(1));
+        assert_eq!(val, MaybeWarn::Ok(1));
+
+        let val: MaybeWarn<i32, &str> = CoreResult::<_, Infallible>::Ok(2).into();
+        assert_eq!(val, MaybeWarn::Ok(2));
+    }
+
+    #[test]
+    fn test_and_then_core() {
+        let res: Result<i32, &str, &str> = Ok(1);
+        assert_eq!(res.and_then_core(|v| CoreResult::Ok(*v + 1)), Ok(2));
+
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.and_then_core(|v| CoreResult::Ok(*v + 1)), Warn(2, "warn"));
+
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.and_then_core(|_| CoreResult::<i32, _>::Err("err")), Err("err"));
+
+        let res: Result<i32, &str, &str> = Err("err");
+        assert_eq!(res.and_then_core(|_| -> CoreResult<i32, _> { unreachable!() }), Err("err"));
+    }
+
+    #[test]
+    fn test_map_or() {
+        let res: Result<i32, &str, &str> = Ok(1);
+        assert_eq!(res.map_or(0, |v| *v + 1), 2);
+        let res: Result<i32, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.map_or(None, |v| v.warn_ref().copied()), Some("warn"));
+        let res: Result<i32, &str, &str> = Err("err");
+        assert_eq!(res.map_or(0, |v| *v + 1), 0);
+    }
+
+    #[test]
+    fn test_map_or_else() {
+        let res: Result<usize, &str, &str> = Ok(1);
+        assert_eq!(res.map_or_else(str::len, |v| v.ok().0), 1);
+        let res: Result<usize, &str, &str> = Warn(1, "warn");
+        assert_eq!(res.map_or_else(str::len, |v| v.ok().1.map_or(0, str::len)), 4);
+        let res: Result<usize, &str, &str> = Err("error");
+        assert_eq!(res.map_or_else(str::len, |v| v.ok().0), 5);
+    }
+
+    #[test]
+    fn test_maybe_warn_accessors() {
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Ok(1);
+        assert_eq!(val.warn_ref(), None);
+        assert_eq!(val.ok(), (1, None));
+        assert_eq!(val.unwrap_value(), 1);
+
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Warn(1, "warn");
+        assert_eq!(val.warn_ref(), Some(&"warn"));
+        assert_eq!(val.ok(), (1, Some("warn")));
+        assert_eq!(val.unwrap_value(), 1);
+    }
+
+    #[test]
+    fn test_maybe_warn_map_or() {
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Ok(1);
+        assert_eq!(val.map_or(0, |v| v + 1), 2);
+
+        // The value is discarded along with the warning
+        let val: MaybeWarn<i32, &str> = MaybeWarn::Warn(1, "warn");
+        assert_eq!(val.map_or(0, |v| v + 1), 0);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn test_log() {
+        use std::sync::Mutex;
+
+        struct Capture(Mutex<Vec<String>>);
+
+        impl log::Log for Capture {
+            fn enabled(&self, _: &log::Metadata<'_>) -> bool {
+                true
+            }
+
+            fn log(&self, record: &log::Record<'_>) {
+                self.0.lock().unwrap().push(format!("{} {}", record.level(), record.args()));
+            }
+
+            fn flush(&self) {}
+        }
+
+        static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+        log::set_logger(&CAPTURE).unwrap();
+        log::set_max_level(log::LevelFilter::Warn);
+
+        let res: Result<i32, &str, &str> = Warn(1, "logged");
+        assert_eq!(res.log_warnings(), Warn(1, "logged"));
+        Result::<(), &str, &str>::Warn((), "reported").report();
+        Result::<(), &str, &str>::Err("failed").report();
+
+        let logs = CAPTURE.0.lock().unwrap();
+        assert_eq!(*logs, ["WARN \"logged\"", "WARN \"reported\"", "ERROR \"failed\""]);
+    }
+}
@@ -1,13 +1,52 @@
+use core::convert::Infallible;
 use core::ops::{Deref, DerefMut};
 
 use MaybeWarn::*;
 
+/// A value which may have a warning attached. This is the output of using `?` on a
+/// [`Result`](super::Result).
+///
+/// # Propagating warnings
+///
+/// `MaybeWarn` doesn't implement `Try`. Using `?` on it would either have to continue with the
+/// value and lose the warning, or short-circuit with the warning and lose the value - and a
+/// `MaybeWarn<T, W>` can't be rebuilt from a warning alone, as `Try` requires. To pass the warning
+/// on from a function returning a [`Result`](super::Result), convert the `MaybeWarn` back with
+/// `Result::from` or `into`, which produces `Ok` or `Warn` as appropriate.
+///
+/// ```
+/// # use trying::warn_result::{MaybeWarn, Result};
+/// fn parse(s: &str) -> Result<u32, (), &'static str> {
+///     match s.trim().parse() {
+///         Ok(val) if s.trim() != s => Result::Warn(val, "surrounding whitespace"),
+///         Ok(val) => Result::Ok(val),
+///         Err(_) => Result::Err(()),
+///     }
+/// }
+///
+/// fn double(s: &str) -> Result<u32, (), &'static str> {
+///     let val: MaybeWarn<u32, _> = parse(s)?;
+///     val.map(|v| v * 2).into()
+/// }
+///
+/// assert_eq!(double("2"), Result::Ok(4));
+/// assert_eq!(double(" 2"), Result::Warn(4, "surrounding whitespace"));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub enum MaybeWarn<T, W> {
     Ok(T),
     Warn(T, W),
 }
 
 impl<T, W> MaybeWarn<T, W> {
+    /// Create a `MaybeWarn` without a warning from an infallible `Result`
+    pub fn from_result(r: Result<T, Infallible>) -> MaybeWarn<T, W> {
+        match r {
+            Result::Ok(val) => Ok(val),
+            Result::Err(err) => match err {},
+        }
+    }
+
     pub fn value(&self) -> &T {
         let (Ok(val) | Warn(val, _)) = self;
         val
@@ -23,6 +62,140 @@ impl<T, W> MaybeWarn<T, W> {
         val
     }
 
+    /// Get the contained value, discarding any warning. Equivalent to
+    /// [`discard_warnings`](Self::discard_warnings).
+    pub fn unwrap_value(self) -> T {
+        self.discard_warnings()
+    }
+
+    /// Split into the contained value and the warning, if there is one
+    pub fn ok(self) -> (T, Option<W>) {
+        match self {
+            Ok(val) => (val, None),
+            Warn(val, warn) => (val, Some(warn)),
+        }
+    }
+
+    /// Get a reference to the warning, if there is one
+    pub fn warn_ref(&self) -> Option<&W> {
+        match self {
+            Ok(_) => None,
+            Warn(_, warn) => Some(warn),
+        }
+    }
+
+    /// Get the warning, or `err` if there isn't one
+    pub fn warn_or<E>(self, err: E) -> Result<W, E> {
+        match self {
+            Ok(_) => Result::Err(err),
+            Warn(_, warn) => Result::Ok(warn),
+        }
+    }
+
+    /// Get the warning
+    ///
+    /// # Panics
+    ///
+    /// If there is no warning, with the provided message
+    #[track_caller]
+    pub fn expect_warn(self, msg: &str) -> W {
+        match self {
+            Ok(_) => panic!("{msg}"),
+            Warn(_, warn) => warn,
+        }
+    }
+
+    /// Apply `f` to the value if there is no warning, otherwise return `default_if_warn`. On a
+    /// `Warn`, both the value and the warning are dropped.
+    pub fn map_or<U>(self, default_if_warn: U, f: impl FnOnce(T) -> U) -> U {
+        match self {
+            Ok(val) => f(val),
+            Warn(_, _) => default_if_warn,
+        }
+    }
+
+    /// Combine two values into a tuple. If both carry a warning, the warning of `other` is
+    /// appended to the warning of `self` with [`Extend`]. See [`zip_with`](Self::zip_with) to
+    /// combine warnings some other way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use trying::warn_result::MaybeWarn;
+    /// let a = MaybeWarn::Warn(1, vec!["a"]);
+    /// let b = MaybeWarn::Warn(2, vec!["b"]);
+    /// assert_eq!(a.zip(b).ok(), ((1, 2), Some(vec!["a", "b"])));
+    /// ```
+    pub fn zip<U>(self, other: MaybeWarn<U, W>) -> MaybeWarn<(T, U), W>
+    where
+        W: IntoIterator + Extend<W::Item>,
+    {
+        self.zip_with(other, |mut a, b| {
+            a.extend(b);
+            a
+        })
+    }
+
+    /// Combine two values into a tuple. If both carry a warning, they are combined with
+    /// `combine`, otherwise the single warning present, if any, is kept.
+    pub fn zip_with<U>(
+        self,
+        other: MaybeWarn<U, W>,
+        combine: impl FnOnce(W, W) -> W,
+    ) -> MaybeWarn<(T, U), W> {
+        match (self, other) {
+            (Ok(a), Ok(b)) => Ok((a, b)),
+            (Warn(a, warn), Ok(b)) | (Ok(a), Warn(b, warn)) => Warn((a, b), warn),
+            (Warn(a, warn_a), Warn(b, warn_b)) => Warn((a, b), combine(warn_a, warn_b)),
+        }
+    }
+
+    /// Apply `f` to the value, producing a new `MaybeWarn`. If both `self` and the output of `f`
+    /// carry a warning, the new warning is appended to the existing one with [`Extend`], so the
+    /// warnings remain in the order they were produced. Otherwise, whichever warning is present,
+    /// if any, is kept. See [`and_then_with`](Self::and_then_with) to combine warnings some
+    /// other way.
+    pub fn and_then<U>(self, f: impl FnOnce(T) -> MaybeWarn<U, W>) -> MaybeWarn<U, W>
+    where
+        W: IntoIterator + Extend<W::Item>,
+    {
+        self.and_then_with(f, |mut a, b| {
+            a.extend(b);
+            a
+        })
+    }
+
+    /// Apply `f` to the value, producing a new `MaybeWarn`. If both `self` and the output of `f`
+    /// carry a warning, they are combined with `combine`, with the existing warning first.
+    /// Otherwise, whichever warning is present, if any, is kept.
+    pub fn and_then_with<U>(
+        self,
+        f: impl FnOnce(T) -> MaybeWarn<U, W>,
+        combine: impl FnOnce(W, W) -> W,
+    ) -> MaybeWarn<U, W> {
+        match self {
+            Ok(val) => f(val),
+            Warn(val, warn) => match f(val) {
+                Ok(out) => Warn(out, warn),
+                Warn(out, new) => Warn(out, combine(warn, new)),
+            },
+        }
+    }
+
+    /// Call `f` with a reference to the value, then return `self` unchanged
+    pub fn inspect(self, f: impl FnOnce(&T)) -> Self {
+        f(self.value());
+        self
+    }
+
+    /// Call `f` with a reference to the warning, if there is one, then return `self` unchanged
+    pub fn inspect_warn(self, f: impl FnOnce(&W)) -> Self {
+        if let Warn(_, warn) = &self {
+            f(warn);
+        }
+        self
+    }
+
     pub fn as_ref(&self) -> MaybeWarn<&T, &W> {
         match self {
             Ok(val) => Ok(val),
@@ -52,6 +225,18 @@ impl<T, W> MaybeWarn<T, W> {
     }
 }
 
+impl<T, W> MaybeWarn<Option<T>, W> {
+    /// Convert a `MaybeWarn<Option<T>, W>` into an `Option<MaybeWarn<T, W>>`. A `None` value
+    /// becomes `None`, discarding any warning, as there's no value left to attach it to. Use
+    /// [`warn_ref`](Self::warn_ref) first if the warning is still needed.
+    pub fn transpose(self) -> Option<MaybeWarn<T, W>> {
+        match self {
+            Ok(val) => val.map(Ok),
+            Warn(val, warn) => val.map(|val| Warn(val, warn)),
+        }
+    }
+}
+
 impl<T, W> Deref for MaybeWarn<T, W> {
     type Target = T;
 
@@ -77,3 +262,9 @@ impl<T, W> AsMut<T> for MaybeWarn<T, W> {
         self.value_mut()
     }
 }
+
+impl<T, W> From<Result<T, Infallible>> for MaybeWarn<T, W> {
+    fn from(r: Result<T, Infallible>) -> Self {
+        MaybeWarn::from_result(r)
+    }
+}
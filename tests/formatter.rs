@@ -0,0 +1,39 @@
+//! Installs a global failure formatter, so lives in its own test binary
+
+use core::panic::{Location, UnwindSafe};
+use std::panic::catch_unwind;
+
+use trying::assert::{self, Assert};
+
+fn json(loc: &Location<'_>, msg: &str) -> String {
+    format!("{{\"message\":{msg:?},\"line\":{}}}", loc.line())
+}
+
+fn panic_msg(f: impl FnOnce() + UnwindSafe) -> String {
+    let payload = catch_unwind(f).unwrap_err();
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .unwrap_or_else(|| String::from(*payload.downcast_ref::<&str>().unwrap()))
+}
+
+#[test]
+fn test_set_formatter() {
+    assert!(assert::set_formatter(json).is_ok());
+    assert!(assert::set_formatter(json).is_err());
+
+    let (a, line) = (Assert::eq(&1, &2), line!());
+    assert_eq!(
+        panic_msg(|| a.to_panic()),
+        format!("{{\"message\":\"Expected `1` to equal `2`\",\"line\":{line}}}")
+    );
+
+    let (a, line) = (Assert::eq(&1, &2), line!());
+    assert_eq!(
+        panic_msg(|| drop(a)),
+        format!(
+            "Failed assertion dropped. (Did you forget a `?` or `to_panic`?)\n\
+             {{\"message\":\"Expected `1` to equal `2`\",\"line\":{line}}}"
+        )
+    );
+}